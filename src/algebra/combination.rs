@@ -0,0 +1,15 @@
+// subsets
+// 0, ..., n-1 から size 個を選ぶ組み合わせを, 添字の昇順で列挙する.
+pub fn subsets(n: usize, size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    let mut ans = Vec::new();
+    for last in size - 1..n {
+        for mut subset in subsets(last, size - 1) {
+            subset.push(last);
+            ans.push(subset);
+        }
+    }
+    ans
+}
@@ -16,4 +16,24 @@ pub trait Field:
 {
     fn unit() -> Self;
     fn zero() -> Self;
+
+    // characteristic
+    // 標数を返す. 標数0の体では0を返す.
+    // 正標数の体としては素体F_pのみを想定し, 任意の元aについてa^p = aであることを用いる.
+    fn characteristic() -> usize;
+
+    // from_usize
+    // 整数nを体の元とみなしたもの, すなわち1をn回足したものを返す.
+    fn from_usize(mut n: usize) -> Self {
+        let mut ans = Self::zero();
+        let mut base = Self::unit();
+        while n > 0 {
+            if n & 1 == 1 {
+                ans = ans + base;
+            }
+            base = base + base;
+            n >>= 1;
+        }
+        ans
+    }
 }
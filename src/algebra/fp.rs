@@ -15,6 +15,12 @@ impl<const P: i32> Fp<P> {
     pub fn new(val: i32) -> Fp<P> {
         Fp::<P> { val: val % P }
     }
+
+    // val
+    // 0以上P未満の代表元を返す.
+    pub fn val(&self) -> i32 {
+        self.val
+    }
 }
 
 impl<const P: i32> Add for Fp<P> {
@@ -71,6 +77,9 @@ impl<const P: i32> Field for Fp<P> {
     fn zero() -> Fp<P> {
         Fp::<P> { val: 0 }
     }
+    fn characteristic() -> usize {
+        P as usize
+    }
 }
 
 impl<const P: i32> fmt::Display for Fp<P> {
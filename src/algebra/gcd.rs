@@ -5,3 +5,48 @@ pub fn ext_gcd(a: i32, b: i32) -> (i32, i32) {
     let (x, y) = ext_gcd(b, a % b);
     (y, x - a / b * y)
 }
+
+pub fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        return a.abs();
+    }
+    gcd(b, a % b)
+}
+
+// chinese_remainder
+// x ≡ a (mod m), x ≡ b (mod p) となる 0 ≤ x < mp を返す. 0 ≤ a < m で, pはmと互いに素な素数とする.
+pub fn chinese_remainder(a: i128, m: i128, b: i128, p: i32) -> i128 {
+    let p128 = p as i128;
+    let (inv, _) = ext_gcd((m % p128) as i32, p);
+    let t = ((b - a) % p128 * inv as i128).rem_euclid(p128);
+    a + m * t
+}
+
+// rational_reconstruction
+// a ≡ r / s (mod m) かつ |r|, s ≤ √(m/2) となる既約分数 r / s (s > 0) を返す. 存在しない場合はNoneを返す.
+// (m, a) にユークリッドの互除法を適用し, 余りが初めて √(m/2) 以下になったところで止める.
+pub fn rational_reconstruction(a: i128, m: i128) -> Option<(i128, i128)> {
+    let bound = isqrt(m / 2);
+    let (mut r0, mut r1) = (m, a.rem_euclid(m));
+    let (mut s0, mut s1) = (0, 1);
+    while r1 > bound {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    if s1 == 0 || s1.abs() > bound || gcd(r1, s1) != 1 {
+        return None;
+    }
+    Some((r1 * s1.signum(), s1.abs()))
+}
+
+fn isqrt(n: i128) -> i128 {
+    let mut x = (n as f64).sqrt() as i128;
+    while x * x > n {
+        x -= 1;
+    }
+    while (x + 1) * (x + 1) <= n {
+        x += 1;
+    }
+    x
+}
@@ -1,3 +1,5 @@
+pub mod combination;
 pub mod field;
 pub mod fp;
 pub mod gcd;
+pub mod rational;
@@ -0,0 +1,150 @@
+use super::field;
+use super::gcd;
+
+use core::fmt;
+use field::Field;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// Rational
+// 有理数体Qの元. 分母は正で, 分子と分母は互いに素になるように保つ.
+// 分子と分母はi128に収まる範囲に限り, 演算の途中で溢れる場合は "rational overflow" でpanicする.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub fn new(num: i64, den: i64) -> Rational {
+        Rational::reduce(num as i128, den as i128)
+    }
+
+    // from_i128
+    // 分子と分母をi128で与えて作る.
+    pub fn from_i128(num: i128, den: i128) -> Rational {
+        Rational::reduce(num, den)
+    }
+
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    fn reduce(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "denominator must not be 0");
+        let g = gcd::gcd(num, den) * den.signum();
+        Rational {
+            num: num / g,
+            den: den / g,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Rational) -> Self {
+        // 分母の最大公約数で先に割っておき, 途中の値が大きくならないようにする.
+        let g = gcd::gcd(self.den, rhs.den);
+        let num = checked(
+            checked(self.num.checked_mul(rhs.den / g))
+                .checked_add(checked(rhs.num.checked_mul(self.den / g))),
+        );
+        Rational::reduce(num, checked(self.den.checked_mul(rhs.den / g)))
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Rational) -> Self::Output {
+        // 分子と相手の分母の最大公約数で先に割っておく.
+        let g1 = gcd::gcd(self.num, rhs.den);
+        let g2 = gcd::gcd(rhs.num, self.den);
+        Rational::reduce(
+            checked((self.num / g1).checked_mul(rhs.num / g2)),
+            checked((self.den / g2).checked_mul(rhs.den / g1)),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, rhs: Rational) -> Self::Output {
+        assert!(rhs.num != 0, "denominator must not be 0");
+        self * Rational {
+            num: rhs.den * rhs.num.signum(),
+            den: rhs.num.abs(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Rational {
+            num: checked(self.num.checked_neg()),
+            den: self.den,
+        }
+    }
+}
+
+fn checked(x: Option<i128>) -> i128 {
+    x.expect("rational overflow")
+}
+
+impl Field for Rational {
+    fn unit() -> Rational {
+        Rational { num: 1, den: 1 }
+    }
+    fn zero() -> Rational {
+        Rational { num: 0, den: 1 }
+    }
+    fn characteristic() -> usize {
+        0
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rational_test() {
+        let x = Rational::new(1, 2);
+        let y = Rational::new(-4, 6);
+        assert_eq!(y, Rational::new(2, -3));
+        assert_eq!(x + y, Rational::new(-1, 6));
+        assert_eq!(x - y, Rational::new(7, 6));
+        assert_eq!(x * y, Rational::new(-1, 3));
+        assert_eq!(x / y, Rational::new(-3, 4));
+        assert_eq!(Rational::new(0, -5), Rational::zero());
+        assert_eq!(format!("{} {}", y, Rational::new(6, 3)), "-2/3 2");
+    }
+
+    #[test]
+    #[should_panic(expected = "rational overflow")]
+    fn rational_overflow() {
+        let x = Rational::new(i64::MAX, 1);
+        let _ = x * x * x;
+    }
+}
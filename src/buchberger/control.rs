@@ -0,0 +1,66 @@
+use crate::algebra::field;
+use crate::polynomial::poly::Polynomial;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+// Stats
+// ブッフベルガーアルゴリズムの計算の統計.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    // 処理したS対の数.
+    pub pairs: usize,
+    // 簡約化して0になったS多項式の数.
+    pub zero_reductions: usize,
+    // 次数の上限を超えたため処理しなかったS対の数.
+    pub skipped_pairs: usize,
+    // 現在の基底の大きさ.
+    pub basis_size: usize,
+    // 基底に含まれる多項式の全次数の最大値.
+    pub max_degree: u32,
+    // 計算開始からの経過時間.
+    pub elapsed: Duration,
+}
+
+// Status
+// 計算がどのように終わったかを表す. Completed以外の場合, 得られた基底はグレブナー基底とは限らない.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    // すべてのS対を処理し, グレブナー基底が得られた.
+    Completed,
+    // cancelがtrueになったため打ち切った.
+    Cancelled,
+    // time_limitを超えたため打ち切った.
+    TimeLimit,
+    // S対の次数がmax_degreeを超えたため処理しなかったS対がある.
+    DegreeLimit,
+}
+
+// Progress
+// 進捗の通知先. 統計を受け取る.
+pub type Progress<'a> = Box<dyn FnMut(&Stats) + 'a>;
+
+// Control
+// 計算の打ち切り条件と進捗の通知先. 既定値ではどれも指定されず, 最後まで計算する.
+#[derive(Default)]
+pub struct Control<'a> {
+    // 計算時間の上限.
+    pub time_limit: Option<Duration>,
+    // S対の次数, すなわち先頭単項式の最小公倍数の全次数の上限.
+    pub max_degree: Option<u32>,
+    // 他のスレッドなどからtrueにすると, 次のS対を処理する前に計算を打ち切る.
+    pub cancel: Option<&'a AtomicBool>,
+    // S対を1つ処理するたびに呼ばれる.
+    pub progress: Option<Progress<'a>>,
+}
+
+// Outcome
+// 打ち切り条件つきで計算した結果. 基底, 終了の状態, 統計をまとめて返す.
+#[derive(Debug, Clone)]
+pub struct Outcome<F, const N: usize>
+where
+    F: field::Field,
+{
+    pub basis: Vec<Polynomial<F, N>>,
+    pub status: Status,
+    pub stats: Stats,
+}
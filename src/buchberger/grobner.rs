@@ -1,38 +1,143 @@
 use crate::polynomial::poly::Polynomial;
 
+use super::control::{Control, Outcome, Stats, Status};
 use super::s_polynomial;
+use super::trace::{Step, Trace};
 use crate::algebra::field;
-use crate::polynomial::poly;
+use crate::polynomial::monomial;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
 
 // buchberger
 // polysで生成される多項式環のイデアルのグレブナー基底を求める.
-pub fn buchberger<F, const N: usize>(mut polys: Vec<Polynomial<F, N>>) -> Vec<Polynomial<F, N>>
+pub fn buchberger<F, const N: usize>(polys: Vec<Polynomial<F, N>>) -> Vec<Polynomial<F, N>>
 where
     F: field::Field,
 {
-    loop {
-        let mut push_poly = None;
-        for f in polys.iter() {
-            for g in polys.iter() {
-                if f == g {
-                    continue;
-                }
-                let (_, s) = poly::simplify(s_polynomial::s_poly(f, g), &polys);
-                if !s.is_zero() {
-                    push_poly = Some(s);
+    run(polys, None, Control::default()).basis
+}
+
+// buchberger_traced
+// buchbergerと同じくグレブナー基底を求め, 計算の過程を記録したものも返す.
+pub fn buchberger_traced<F, const N: usize>(
+    polys: Vec<Polynomial<F, N>>,
+) -> (Vec<Polynomial<F, N>>, Trace<F, N>)
+where
+    F: field::Field,
+{
+    let mut trace = Trace::new(polys.clone());
+    let grobner = run(polys, Some(&mut trace), Control::default()).basis;
+    (grobner, trace)
+}
+
+// buchberger_controlled
+// controlの打ち切り条件のもとでグレブナー基底を求める.
+// 打ち切った場合も, それまでに得られた基底を終了の状態と統計とともに返す.
+pub fn buchberger_controlled<F, const N: usize>(
+    polys: Vec<Polynomial<F, N>>,
+    control: Control,
+) -> Outcome<F, N>
+where
+    F: field::Field,
+{
+    run(polys, None, control)
+}
+
+// run
+// 未処理のS対を順に取り出し, S多項式をその時点の基底で簡約化して0でなければ基底に加える.
+// traceが与えられた場合は各段階を記録し, controlの条件を満たさなくなったら打ち切る.
+fn run<F, const N: usize>(
+    mut polys: Vec<Polynomial<F, N>>,
+    mut trace: Option<&mut Trace<F, N>>,
+    mut control: Control,
+) -> Outcome<F, N>
+where
+    F: field::Field,
+{
+    let start = Instant::now();
+    let mut stats = Stats {
+        basis_size: polys.len(),
+        max_degree: polys
+            .iter()
+            .map(|poly| poly.total_degree())
+            .max()
+            .unwrap_or(0),
+        ..Stats::default()
+    };
+    let mut status = Status::Completed;
+    let mut pairs: VecDeque<(usize, usize)> = (0..polys.len())
+        .flat_map(|j| (0..j).map(move |i| (i, j)))
+        .collect();
+    while let Some((i, j)) = pairs.pop_front() {
+        if control
+            .cancel
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+        {
+            status = Status::Cancelled;
+            break;
+        }
+        if control
+            .time_limit
+            .is_some_and(|limit| start.elapsed() > limit)
+        {
+            status = Status::TimeLimit;
+            break;
+        }
+        if let Some(max_degree) = control.max_degree {
+            if monomial::lcm(&polys[i].monos[0], &polys[j].monos[0]).total_degree() > max_degree {
+                stats.skipped_pairs += 1;
+                status = Status::DegreeLimit;
+                continue;
+            }
+        }
+        stats.pairs += 1;
+        let mut s = s_polynomial::s_poly(&polys[i], &polys[j]);
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(Step::Pair(i, j, s.clone()));
+        }
+        let mut is_simplified = true;
+        while is_simplified {
+            is_simplified = false;
+            for (k, rhs) in polys.iter().enumerate() {
+                if s.simplify(rhs) {
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.push(Step::Reduction(k, s.clone()));
+                    }
+                    is_simplified = true;
                     break;
                 }
             }
-            if push_poly.is_some() {
-                break;
+        }
+        if s.is_zero() {
+            stats.zero_reductions += 1;
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(Step::Zero);
             }
+        } else {
+            let k = polys.len();
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(Step::Added(k, s.clone()));
+            }
+            stats.max_degree = stats.max_degree.max(s.total_degree());
+            polys.push(s);
+            pairs.extend((0..k).map(|i| (i, k)));
         }
-        match push_poly {
-            Some(s) => polys.push(s),
-            None => break,
+        stats.basis_size = polys.len();
+        stats.elapsed = start.elapsed();
+        if let Some(progress) = control.progress.as_mut() {
+            progress(&stats);
         }
     }
-    polys
+    stats.elapsed = start.elapsed();
+    if let Some(trace) = trace {
+        trace.set_result(polys.clone());
+    }
+    Outcome {
+        basis: polys,
+        status,
+        stats,
+    }
 }
 
 // minimal_grobner
@@ -44,8 +149,12 @@ where
     let mut pick_index = vec![];
     for (i, poly) in grobner.iter().enumerate() {
         let mut can_div = false;
-        for f in grobner.iter() {
-            if poly != f && poly.monos[0].can_divide(&f.monos[0]) {
+        for (j, f) in grobner.iter().enumerate() {
+            // 先頭単項式が等しいものが複数ある場合は最初のものだけを残す.
+            if i != j
+                && poly.monos[0].can_divide(&f.monos[0])
+                && (poly.monos[0] != f.monos[0] || j < i)
+            {
                 can_div = true;
                 break;
             }
@@ -104,3 +213,14 @@ where
     }
     ans
 }
+
+// reduced_grobner
+// polysで生成されるイデアルの簡約グレブナー基底を求める.
+// 0である多項式は取り除いてから計算する.
+pub fn reduced_grobner<F, const N: usize>(polys: Vec<Polynomial<F, N>>) -> Vec<Polynomial<F, N>>
+where
+    F: field::Field,
+{
+    let polys = polys.into_iter().filter(|poly| !poly.is_zero()).collect();
+    simplified_grobner(minimal_grobner(buchberger(polys)))
+}
@@ -1,5 +1,8 @@
+pub mod control;
 pub mod grobner;
 pub mod s_polynomial;
+pub mod syzygy;
+pub mod trace;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,81 @@
+use super::grobner::buchberger_traced;
+use super::trace::Step;
+use crate::algebra::field;
+use crate::polynomial::monomial;
+use crate::polynomial::poly::Polynomial;
+
+// syzygies
+// Σ a_i polys[i] = 0 となる (a_1, ..., a_r) 全体のなす加群(syzygy加群)の生成系を返す.
+// ブッフベルガーアルゴリズムの計算過程を記録し, 基底の各元を入力の多項式の組み合わせで表す.
+// S対 (g_i, g_j) のS多項式を簡約化して0になったとき, その組み合わせの係数がsyzygyになる.
+// 基底に元を加えたS対から得られる関係は入力の組み合わせでは自明になるので,
+// Schreyerの定理により0に簡約化されたS対から得られるものが生成系になる.
+// 0である入力polys[i]に対しては単位ベクトルe_iを加える.
+pub fn syzygies<F, const N: usize>(polys: &[Polynomial<F, N>]) -> Vec<Vec<Polynomial<F, N>>>
+where
+    F: field::Field,
+{
+    let r = polys.len();
+    let unit = |i: usize| {
+        let mut e = vec![Polynomial::new(vec![]); r];
+        e[i] = Polynomial::constant(F::unit());
+        e
+    };
+    let nonzero: Vec<usize> = (0..r).filter(|&i| !polys[i].is_zero()).collect();
+    let mut ans: Vec<Vec<Polynomial<F, N>>> =
+        (0..r).filter(|&i| polys[i].is_zero()).map(unit).collect();
+    let (_, trace) = buchberger_traced(nonzero.iter().map(|&i| polys[i].clone()).collect());
+
+    // basis[k] = Σ cofactors[k][i] polys[i]
+    let mut basis = trace.input.clone();
+    let mut cofactors: Vec<Vec<Polynomial<F, N>>> = nonzero.iter().map(|&i| unit(i)).collect();
+    let mut current: Vec<Polynomial<F, N>> = Vec::new();
+    let mut s = Polynomial::new(vec![]);
+    for step in trace.steps.into_iter() {
+        match step {
+            Step::Pair(i, j, poly) => {
+                let lcm = monomial::lcm(&basis[i].monos[0], &basis[j].monos[0]);
+                let a = lcm.div(&basis[i].monos[0]);
+                let b = lcm.div(&basis[j].monos[0]);
+                current = (0..r)
+                    .map(|l| cofactors[i][l].mul(&a).sub(&cofactors[j][l].mul(&b)))
+                    .collect();
+                s = poly;
+            }
+            Step::Reduction(k, poly) => {
+                // s - poly = q basis[k] となる単項式q
+                let q = s.sub(&poly).div_exact(&basis[k]).unwrap();
+                for (c, d) in current.iter_mut().zip(cofactors[k].iter()) {
+                    *c = c.sub(&d.mul_poly(&q));
+                }
+                s = poly;
+            }
+            Step::Zero => {
+                if current.iter().any(|c| !c.is_zero()) {
+                    ans.push(current.clone());
+                }
+            }
+            Step::Added(_, poly) => {
+                basis.push(poly);
+                cofactors.push(current.clone());
+            }
+        }
+    }
+    ans
+}
+
+// is_syzygy
+// Σ coefs[i] polys[i] = 0 か判定する.
+pub fn is_syzygy<F, const N: usize>(polys: &[Polynomial<F, N>], coefs: &[Polynomial<F, N>]) -> bool
+where
+    F: field::Field,
+{
+    polys.len() == coefs.len()
+        && polys
+            .iter()
+            .zip(coefs.iter())
+            .fold(Polynomial::new(vec![]), |acc, (f, a)| {
+                acc.add(&f.mul_poly(a))
+            })
+            .is_zero()
+}
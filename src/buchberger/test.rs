@@ -2,8 +2,13 @@ use crate::algebra::fp;
 use crate::buchberger::grobner::minimal_grobner;
 use crate::polynomial::monomial::Monomial;
 use crate::polynomial::poly::Polynomial;
+use std::cell::Cell;
+use std::sync::atomic::AtomicBool;
 
-use super::grobner::{buchberger, simplified_grobner};
+use super::control::{Control, Status};
+use super::grobner::{buchberger, buchberger_controlled, buchberger_traced, simplified_grobner};
+use super::syzygy::{is_syzygy, syzygies};
+use super::trace::Step;
 
 type Fp = fp::Fp<5>;
 
@@ -86,3 +91,145 @@ fn simple_grobner() {
         ]
     )
 }
+
+#[test]
+fn trace_test() {
+    let ideal = vec![
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [2, 1]),
+            Monomial::new(Fp::new(4), [0, 0]),
+        ]),
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [1, 2]),
+            Monomial::new(Fp::new(4), [1, 0]),
+        ]),
+    ];
+    let (grobner, trace) = buchberger_traced(ideal.clone());
+    assert_eq!(grobner, buchberger(ideal));
+    assert_eq!(trace.result, grobner);
+    assert_eq!(
+        trace.steps[..2],
+        [
+            Step::Pair(
+                0,
+                1,
+                Polynomial::new(vec![
+                    Monomial::new(Fp::new(1), [2, 0]),
+                    Monomial::new(Fp::new(4), [0, 1]),
+                ])
+            ),
+            Step::Added(
+                2,
+                Polynomial::new(vec![
+                    Monomial::new(Fp::new(1), [2, 0]),
+                    Monomial::new(Fp::new(4), [0, 1]),
+                ])
+            ),
+        ]
+    );
+    let added = trace
+        .steps
+        .iter()
+        .filter(|step| matches!(step, Step::Added(_, _)))
+        .count();
+    assert_eq!(added, grobner.len() - 2);
+    let text = trace.to_text();
+    assert!(text.starts_with("Input:\n  f_1 = x_1^2x_2^1+4\n"));
+    assert!(text.contains("S(f_1, f_2) = x_1^2+4x_2^1\n  added f_3 = x_1^2+4x_2^1\n"));
+    assert!(trace.to_markdown().contains("### S(f_1, f_2)"));
+}
+
+#[test]
+fn controlled_test() {
+    let ideal = vec![
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [2, 1]),
+            Monomial::new(Fp::new(4), [0, 0]),
+        ]),
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [1, 2]),
+            Monomial::new(Fp::new(4), [1, 0]),
+        ]),
+    ];
+
+    let calls = Cell::new(0);
+    let outcome = buchberger_controlled(
+        ideal.clone(),
+        Control {
+            progress: Some(Box::new(|_| calls.set(calls.get() + 1))),
+            ..Control::default()
+        },
+    );
+    assert_eq!(outcome.status, Status::Completed);
+    assert_eq!(outcome.basis, buchberger(ideal.clone()));
+    assert_eq!(outcome.stats.pairs, 6);
+    assert_eq!(outcome.stats.zero_reductions, 4);
+    assert_eq!(outcome.stats.basis_size, 4);
+    assert_eq!(outcome.stats.max_degree, 3);
+    assert_eq!(calls.get(), 6);
+
+    let cancel = AtomicBool::new(true);
+    let outcome = buchberger_controlled(
+        ideal.clone(),
+        Control {
+            cancel: Some(&cancel),
+            ..Control::default()
+        },
+    );
+    assert_eq!(outcome.status, Status::Cancelled);
+    assert_eq!(outcome.basis, ideal);
+    assert_eq!(outcome.stats.pairs, 0);
+
+    let outcome = buchberger_controlled(
+        ideal.clone(),
+        Control {
+            max_degree: Some(3),
+            ..Control::default()
+        },
+    );
+    assert_eq!(outcome.status, Status::DegreeLimit);
+    assert_eq!(outcome.stats.skipped_pairs, 1);
+    assert_eq!(outcome.basis, ideal);
+}
+
+#[test]
+fn syzygy_test() {
+    let x = Polynomial::<Fp, 2>::variable(0);
+    let y = Polynomial::<Fp, 2>::variable(1);
+    // (x, y) の syzygy 加群は (y, -x) で生成される
+    assert_eq!(
+        syzygies(&[x.clone(), y.clone()]),
+        vec![vec![y.clone(), x.neg()]]
+    );
+
+    // (x, y, x + y) では次数0の関係 (1, 1, -1) も得られる
+    let one = Polynomial::constant(Fp::new(1));
+    let syz = syzygies(&[x.clone(), y.clone(), x.add(&y)]);
+    assert!(syz.contains(&vec![one.clone(), one.clone(), one.neg()]));
+    assert!(syz.contains(&vec![y.clone(), x.neg(), Polynomial::new(vec![])]));
+
+    // 0 を含む場合は単位ベクトルが加わる
+    let zero = Polynomial::new(vec![]);
+    let syz = syzygies(&[x.clone(), zero.clone()]);
+    assert_eq!(
+        syz,
+        vec![vec![zero.clone(), Polynomial::constant(Fp::new(1))]]
+    );
+
+    // x^2 y - 1, x y^2 - x から基底に元が加わる場合も, 得られるものはすべて syzygy である
+    let polys = vec![
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [2, 1]),
+            Monomial::new(Fp::new(4), [0, 0]),
+        ]),
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [1, 2]),
+            Monomial::new(Fp::new(4), [1, 0]),
+        ]),
+        x.add(&y),
+    ];
+    let syz = syzygies(&polys);
+    assert!(!syz.is_empty());
+    assert!(syz.iter().all(|a| is_syzygy(&polys, a)));
+    assert!(!is_syzygy(&polys, &[x.clone(), y.clone(), zero]));
+}
@@ -0,0 +1,116 @@
+use crate::algebra::field;
+use crate::polynomial::poly::{show, Polynomial};
+use std::fmt;
+
+// Step
+// ブッフベルガーアルゴリズムの1段階. 添字は基底の中での位置を表す.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step<F, const N: usize>
+where
+    F: field::Field,
+{
+    // S対(i, j)を取り出し, S多項式を計算した.
+    Pair(usize, usize, Polynomial<F, N>),
+    // 基底のk番目の多項式で1回簡約化した結果.
+    Reduction(usize, Polynomial<F, N>),
+    // 簡約化の結果が0になり, 基底には加えなかった.
+    Zero,
+    // 簡約化の結果が0でなく, 基底のk番目として加えた.
+    Added(usize, Polynomial<F, N>),
+}
+
+// Trace
+// ブッフベルガーアルゴリズムの計算過程の記録. 入力, 各段階, 得られた基底を保持する.
+#[derive(Debug, Clone)]
+pub struct Trace<F, const N: usize>
+where
+    F: field::Field,
+{
+    pub input: Vec<Polynomial<F, N>>,
+    pub steps: Vec<Step<F, N>>,
+    pub result: Vec<Polynomial<F, N>>,
+}
+
+impl<F, const N: usize> Trace<F, N>
+where
+    F: field::Field,
+{
+    pub fn new(input: Vec<Polynomial<F, N>>) -> Trace<F, N> {
+        Trace {
+            input,
+            steps: Vec::new(),
+            result: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, step: Step<F, N>) {
+        self.steps.push(step);
+    }
+
+    pub fn set_result(&mut self, result: Vec<Polynomial<F, N>>) {
+        self.result = result;
+    }
+}
+
+impl<F, const N: usize> Trace<F, N>
+where
+    F: field::Field + fmt::Display,
+{
+    // to_text
+    // 計算過程を平文で表示する. 基底の多項式は1から番号をつけてf_1, f_2, ...と呼ぶ.
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("Input:\n");
+        for (i, poly) in self.input.iter().enumerate() {
+            out += &format!("  f_{} = {}\n", i + 1, show(poly));
+        }
+        for step in self.steps.iter() {
+            out += &match step {
+                Step::Pair(i, j, s) => {
+                    format!("S(f_{}, f_{}) = {}\n", i + 1, j + 1, show(s))
+                }
+                Step::Reduction(k, poly) => format!("  reduce by f_{} -> {}\n", k + 1, show(poly)),
+                Step::Zero => "  reduces to 0, discarded\n".to_string(),
+                Step::Added(k, poly) => format!("  added f_{} = {}\n", k + 1, show(poly)),
+            };
+        }
+        out += "Result:\n";
+        for (i, poly) in self.result.iter().enumerate() {
+            out += &format!("  f_{} = {}\n", i + 1, show(poly));
+        }
+        out
+    }
+
+    // to_markdown
+    // 計算過程をMarkdownで表示する. S対ごとに節を分け, 簡約化の各段階を番号付きの箇条書きにする.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("## Input\n\n");
+        for (i, poly) in self.input.iter().enumerate() {
+            out += &format!("- f_{} = `{}`\n", i + 1, show(poly));
+        }
+        let mut reduction = 0;
+        for step in self.steps.iter() {
+            out += &match step {
+                Step::Pair(i, j, s) => {
+                    reduction = 0;
+                    format!(
+                        "\n### S(f_{}, f_{})\n\nS-polynomial: `{}`\n\n",
+                        i + 1,
+                        j + 1,
+                        show(s)
+                    )
+                }
+                Step::Reduction(k, poly) => {
+                    reduction += 1;
+                    format!("{}. reduce by f_{}: `{}`\n", reduction, k + 1, show(poly))
+                }
+                Step::Zero => "\n**Reduces to 0.**\n".to_string(),
+                Step::Added(k, poly) => format!("\n**Added f_{} = `{}`.**\n", k + 1, show(poly)),
+            };
+        }
+        out += "\n## Result\n\n";
+        for (i, poly) in self.result.iter().enumerate() {
+            out += &format!("- f_{} = `{}`\n", i + 1, show(poly));
+        }
+        out
+    }
+}
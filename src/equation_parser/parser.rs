@@ -107,6 +107,9 @@ impl Parser {
         let mut degree = [0; N];
         while let Some(&Token::Var('x')) = self.read() {
             match self.var() {
+                Ok((num, _)) if num == 0 || num as usize > N => {
+                    return Err("variable index out of range")
+                }
                 Ok((num, deg)) => degree[(num - 1) as usize] += deg,
                 Err(msg) => return Err(msg),
             }
@@ -0,0 +1,7 @@
+pub mod multivariate;
+pub mod squarefree;
+pub mod univariate;
+pub mod zassenhaus;
+
+#[cfg(test)]
+mod test;
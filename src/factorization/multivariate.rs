@@ -0,0 +1,513 @@
+use super::squarefree;
+use super::univariate as uni;
+use super::zassenhaus::{self, IntegerPolynomial};
+use crate::algebra::combination::subsets;
+use crate::algebra::field::{self, Field};
+use crate::algebra::fp::Fp;
+use crate::algebra::gcd as integer;
+use crate::algebra::rational::Rational;
+use crate::polynomial::gcd::{self, Gcd};
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::{coefficients_in, embed, Univariate};
+
+// 評価点を探すときに試す点の数の上限.
+const MAX_POINTS: usize = 1000;
+
+// Factorization
+// 既約分解の結果. 定数倍と, 先頭係数が1の既約因子とその重複度の組.
+pub type Factorization<F, const N: usize> = (F, Vec<(Polynomial<F, N>, usize)>);
+
+// Factorize
+// 多変数多項式を既約分解できる係数体. F_Pでは factor, Qでは factor_rational を用いる.
+// rootsは1変数多項式のFにおける根を重複なく返す. F_Pでは Univariate::roots を用いる.
+pub trait Factorize: Gcd {
+    fn factorize<const N: usize, const M: usize>(
+        f: &Polynomial<Self, N>,
+    ) -> Result<Factorization<Self, N>, &'static str>;
+    fn roots(f: &Univariate<Self>) -> Result<Vec<Self>, &'static str>;
+}
+
+impl<const P: i32> Factorize for Fp<P> {
+    fn factorize<const N: usize, const M: usize>(
+        f: &Polynomial<Fp<P>, N>,
+    ) -> Result<Factorization<Fp<P>, N>, &'static str> {
+        factor::<P, N, M>(f)
+    }
+
+    fn roots(f: &Univariate<Fp<P>>) -> Result<Vec<Fp<P>>, &'static str> {
+        Ok(f.roots())
+    }
+}
+
+impl Factorize for Rational {
+    fn factorize<const N: usize, const M: usize>(
+        f: &Polynomial<Rational, N>,
+    ) -> Result<Factorization<Rational, N>, &'static str> {
+        factor_rational::<N, M>(f)
+    }
+
+    // 既約分解して1次の因子から根を取り出す.
+    fn roots(f: &Univariate<Rational>) -> Result<Vec<Rational>, &'static str> {
+        if f.degree() == 0 {
+            return Ok(vec![]);
+        }
+        let (_, factors) = factor_rational::<1, 2>(&f.to_poly())?;
+        Ok(factors
+            .iter()
+            .map(|(g, _)| Univariate::from_poly(g))
+            .filter(|g| g.degree() == 1)
+            .map(|g| -g.coefs[0] / g.coefs[1])
+            .collect())
+    }
+}
+
+// factor
+// F_P上のN変数多項式を既約分解する. 定数倍と, 先頭係数が1の既約因子とその重複度の組を返す.
+// 主変数x_kを1つ選び, f / gcd(f, ∂f/∂x_k) で無平方かつどの因子もx_kを含む部分を取り出して分解し,
+// その因子でfを割り切れるだけ割る. これを繰り返し, どの変数で微分しても0になればfはP乗なのでP乗根をとる.
+// 無平方な部分は, 他の変数に値を代入した1変数多項式を分解し, ヘンゼル持ち上げで多変数の因子を復元する.
+// 多変数の最大公約数を使うため M = N+1 とする.
+// Fが小さく, 代入しても無平方のままになる点が見つからない場合はエラーを返す.
+// Q上の分解は factor_rational で行う.
+pub fn factor<const P: i32, const N: usize, const M: usize>(
+    f: &Polynomial<Fp<P>, N>,
+) -> Result<Factorization<Fp<P>, N>, &'static str> {
+    if f.is_zero() {
+        return Err("cannot factor 0");
+    }
+    let lc = f.monos[0].coef;
+    let mut factors = Vec::new();
+    factor_monic::<P, N, M>(monic(f), 1, &mut factors)?;
+    factors.sort_by(|(g, _), (h, _)| h.monos[0].partial_cmp(&g.monos[0]).unwrap());
+    Ok((lc, factors))
+}
+
+fn factor_monic<const P: i32, const N: usize, const M: usize>(
+    mut f: Polynomial<Fp<P>, N>,
+    multiplicity: usize,
+    factors: &mut Vec<(Polynomial<Fp<P>, N>, usize)>,
+) -> Result<(), &'static str> {
+    while f.total_degree() > 0 {
+        let k = match (0..N).find(|&k| !f.derivative(k).is_zero()) {
+            Some(k) => k,
+            None => {
+                // fはP乗なので, 各変数の次数をPで割ったものがP乗根になる. F_Pではa^P = aである.
+                let root = Polynomial::new(
+                    f.monos
+                        .iter()
+                        .map(|mono| Monomial::new(mono.coef, mono.degree.map(|deg| deg / P as u32)))
+                        .collect(),
+                );
+                return factor_monic::<P, N, M>(root, multiplicity * P as usize, factors);
+            }
+        };
+        let g = gcd::gcd::<Fp<P>, N, M>(&f, &f.derivative(k))?;
+        let u = f.div_exact(&g).unwrap();
+        for h in factor_squarefree::<P, N, M>(u, k)? {
+            let mut e = 0;
+            while let Some(quo) = f.div_exact(&h) {
+                f = quo;
+                e += 1;
+            }
+            factors.push((h, e * multiplicity));
+        }
+    }
+    Ok(())
+}
+
+// factor_squarefree
+// 無平方で, どの既約因子もx_kを含む多項式uを既約分解し, 先頭係数が1の因子を返す.
+fn factor_squarefree<const P: i32, const N: usize, const M: usize>(
+    u: Polynomial<Fp<P>, N>,
+    k: usize,
+) -> Result<Vec<Polynomial<Fp<P>, N>>, &'static str> {
+    let point =
+        evaluation_point(&u, k).ok_or("no good evaluation point, the field is too small")?;
+    let shift = point.map(|a| -a);
+    // 評価点が原点になるように平行移動する.
+    let v = translate(&u, &point);
+    let image = coefficients_in(&v, k)
+        .remove(&[0; N])
+        .unwrap_or_else(Univariate::zero);
+    let (_, image_factors) = uni::factor(&image);
+    if image_factors.len() == 1 {
+        return Ok(vec![monic(&u)]);
+    }
+    let image_factors: Vec<Univariate<Fp<P>>> = image_factors.into_iter().map(|(g, _)| g).collect();
+    let bound = v
+        .monos
+        .iter()
+        .map(|mono| mono.total_degree() - mono.degree[k])
+        .max()
+        .unwrap_or(0);
+    let lc = lead_coef(&v, k);
+    let mut lifted = hensel_lift(&v, &lc, k, bound, &image_factors);
+    let mut lifted_images = image_factors;
+
+    // 持ち上げた因子の部分集合を小さいものから試し, 真の因子を見つけたら取り除く.
+    let mut ans = Vec::new();
+    let mut v = v;
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let mut found = false;
+        for subset in subsets(lifted.len(), size) {
+            let candidate = subset.iter().fold(lc.clone(), |acc, &i| {
+                truncate(&acc.mul_poly(&lifted[i]), k, bound)
+            });
+            let h = gcd::gcd::<Fp<P>, N, M>(&v, &candidate)?;
+            let expected = subset
+                .iter()
+                .fold(Univariate::constant(Fp::unit()), |acc, &i| {
+                    acc.mul(&lifted_images[i])
+                });
+            let image = coefficients_in(&h, k)
+                .remove(&[0; N])
+                .unwrap_or_else(Univariate::zero);
+            if image.monic() == expected {
+                v = v.div_exact(&h).unwrap();
+                ans.push(monic(&translate(&h, &shift)));
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                    lifted_images.remove(i);
+                }
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            size += 1;
+        }
+    }
+    if v.total_degree() > 0 {
+        ans.push(monic(&translate(&v, &shift)));
+    }
+    Ok(ans)
+}
+
+// factor_rational
+// Q上のN変数多項式を既約分解する. 定数倍と, 先頭係数が1の既約因子とその重複度の組を返す.
+// 無平方分解した各部分を factor_squarefree_rational で分解する. 多変数の最大公約数を使うため M = N+1 とする.
+// 1変数に直した像をZ上で分解するので, 扱える大きさは像の次数で決まる. 各変数の次数をd_i,
+// 先頭単項式の次数をe_iとすると像の次数は n = Σ e_i Π_{j>i} (d_j + 1) であり, 像がpを法として分解するとき
+// ミニョットの評価 2^n ||f||_2 の2|lc|倍を超える法p^kが2^62未満でなければエラーを返す.
+// 例えば (x^7 + y + 1)(y^7 + x - 1) は n = 72 なので分解できない.
+pub fn factor_rational<const N: usize, const M: usize>(
+    f: &Polynomial<Rational, N>,
+) -> Result<Factorization<Rational, N>, &'static str> {
+    if f.is_zero() {
+        return Err("cannot factor 0");
+    }
+    let lc = f.monos[0].coef;
+    let mut factors = Vec::new();
+    for (g, e) in squarefree::squarefree::<Rational, N, M>(f)? {
+        for h in factor_squarefree_rational(&g)? {
+            factors.push((h, e));
+        }
+    }
+    factors.sort_by(|(g, _), (h, _)| h.monos[0].partial_cmp(&g.monos[0]).unwrap());
+    Ok((lc, factors))
+}
+
+// factor_squarefree_rational
+// 無平方な多項式を既約分解し, 先頭係数が1の因子を返す.
+// 変数で割り切れる分を取り出してから分母を払い, 原始的な整数係数の多項式Fにする.
+// 各変数を平行移動し, x_i ↦ x^{w_i} (クロネッカー代入) で1変数多項式にして zassenhaus::factor_integer で分解する.
+// w_i はFの各変数の次数より大きい基数による位取りなので, Fの因子の像からもとの因子が一意に戻る.
+// 像が無平方にならない場合は, 平行移動の量と基数を変えて試し直す.
+fn factor_squarefree_rational<const N: usize>(
+    g: &Polynomial<Rational, N>,
+) -> Result<Vec<Polynomial<Rational, N>>, &'static str> {
+    let mut g = integer_part(g);
+    let mut ans = Vec::new();
+    for i in 0..N {
+        if g.monos.iter().all(|mono| mono.degree[i] > 0) {
+            let x = Polynomial::variable(i);
+            g = g.div_exact(&x).unwrap();
+            ans.push(x);
+        }
+    }
+    if g.total_degree() == 0 {
+        return Ok(ans);
+    }
+    let degrees: [u32; N] =
+        std::array::from_fn(|i| g.monos.iter().map(|mono| mono.degree[i]).max().unwrap());
+    let mut err = "";
+    for extra in 0..3 {
+        for s in [0, 1, -1, 2, -2] {
+            let radix = degrees.map(|d| d + 1 + extra);
+            let shifted = translate(&g, &[Rational::new(s, 1); N]);
+            match factor_kronecker(&shifted, &radix) {
+                Ok(factors) => {
+                    let shift = [Rational::new(-s, 1); N];
+                    ans.extend(factors.iter().map(|h| monic(&translate(h, &shift))));
+                    return Ok(ans);
+                }
+                Err(e) => err = e,
+            }
+        }
+    }
+    Err(err)
+}
+
+// factor_kronecker
+// 整数係数の無平方な多項式fを, 各変数の次数がradix未満であることを用いてクロネッカー代入で分解する.
+// 1変数の既約因子の部分集合の積を小さいものから試し, もとに戻したものがfを割り切れば因子として取り除く.
+fn factor_kronecker<const N: usize>(
+    f: &Polynomial<Rational, N>,
+    radix: &[u32; N],
+) -> Result<Vec<Polynomial<Rational, N>>, &'static str> {
+    let mut weights = [1u64; N];
+    for i in (0..N.saturating_sub(1)).rev() {
+        weights[i] = weights[i + 1] * radix[i + 1] as u64;
+    }
+    let mut f = f.clone();
+    let mut image = vec![
+        0;
+        f.monos[0]
+            .degree
+            .iter()
+            .zip(weights.iter())
+            .map(|(&d, &w)| d as u64 * w)
+            .sum::<u64>() as usize
+            + 1
+    ];
+    for mono in f.monos.iter() {
+        let e: u64 = mono
+            .degree
+            .iter()
+            .zip(weights.iter())
+            .map(|(&d, &w)| d as u64 * w)
+            .sum();
+        image[e as usize] = mono.coef.num();
+    }
+    let mut factors = zassenhaus::factor_integer(&image)?;
+    let mut ans = Vec::new();
+    let mut size = 1;
+    while 2 * size <= factors.len() {
+        let found = subsets(factors.len(), size).into_iter().find_map(|subset| {
+            let product = subset
+                .iter()
+                .try_fold(vec![1], |acc, &i| zassenhaus::mul_exact(&acc, &factors[i]))?;
+            let quo = zassenhaus::div_exact(&image, &product)?;
+            let h = inverse_kronecker(&product, radix, &weights);
+            let q = inverse_kronecker(&quo, radix, &weights);
+            let hq = h.mul_poly(&q);
+            (hq == f || hq == f.neg()).then_some((subset, h, q, quo))
+        });
+        match found {
+            Some((subset, h, q, quo)) => {
+                for &i in subset.iter().rev() {
+                    factors.remove(i);
+                }
+                ans.push(h);
+                f = q;
+                image = quo;
+            }
+            None => size += 1,
+        }
+    }
+    if f.total_degree() > 0 {
+        ans.push(f);
+    }
+    Ok(ans)
+}
+
+// inverse_kronecker
+// クロネッカー代入の逆. x^e を, eを基数radixで位取りした次数の単項式に戻す.
+fn inverse_kronecker<const N: usize>(
+    u: &IntegerPolynomial,
+    radix: &[u32; N],
+    weights: &[u64; N],
+) -> Polynomial<Rational, N> {
+    Polynomial::new(
+        u.iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, &c)| c != 0)
+            .map(|(e, &c)| {
+                let degree = std::array::from_fn(|i| {
+                    let digit = e as u64 / weights[i];
+                    if i == 0 {
+                        digit as u32
+                    } else {
+                        (digit % radix[i] as u64) as u32
+                    }
+                });
+                Monomial::new(Rational::from_i128(c, 1), degree)
+            })
+            .collect(),
+    )
+}
+
+// integer_part
+// 分母を払って係数の最大公約数で割り, 原始的な整数係数の多項式にする.
+fn integer_part<const N: usize>(f: &Polynomial<Rational, N>) -> Polynomial<Rational, N> {
+    let den = f.monos.iter().fold(1, |acc, mono| {
+        acc / integer::gcd(acc, mono.coef.den()) * mono.coef.den()
+    });
+    let content = f
+        .monos
+        .iter()
+        .fold(0, |acc, mono| integer::gcd(acc, mono.coef.num()));
+    f.mul(&Monomial::new(Rational::from_i128(den, content), [0; N]))
+}
+
+// evaluation_point
+// x_k以外の変数に代入したときに, x_kについての先頭係数が0にならず無平方のままになる点を探す.
+// x_kの成分は使わない.
+fn evaluation_point<const P: i32, const N: usize>(
+    u: &Polynomial<Fp<P>, N>,
+    k: usize,
+) -> Option<[Fp<P>; N]> {
+    let degree = u.monos.iter().map(|mono| mono.degree[k]).max().unwrap_or(0);
+    let count = (0..N - 1)
+        .fold(1usize, |acc, _| acc.saturating_mul(P as usize))
+        .min(MAX_POINTS);
+    for index in 0..count {
+        let mut point = [Fp::zero(); N];
+        let mut rest = index;
+        for (j, a) in point.iter_mut().enumerate() {
+            if j != k {
+                *a = Fp::new((rest % P as usize) as i32);
+                rest /= P as usize;
+            }
+        }
+        let image = coefficients_in(&translate(u, &point), k)
+            .remove(&[0; N])
+            .unwrap_or_else(Univariate::zero);
+        if image.degree() == degree as usize && image.gcd(&image.derivative()).degree() == 0 {
+            return Some(point);
+        }
+    }
+    None
+}
+
+// hensel_lift
+// vのx_k以外の変数を0とした像がモニックな互いに素な1変数多項式factorsの積の定数倍であるとき,
+// lc^{-1} v をx_kについてモニックな因子の積に, x_k以外の変数についての次数boundまで持ち上げる.
+fn hensel_lift<F, const N: usize>(
+    v: &Polynomial<F, N>,
+    lc: &Polynomial<F, N>,
+    k: usize,
+    bound: u32,
+    factors: &[Univariate<F>],
+) -> Vec<Polynomial<F, N>>
+where
+    F: field::Field,
+{
+    // x_k以外の変数についての冪級数としてlcの逆元を求める.
+    let c0 = F::unit() / lc.monos.last().unwrap().coef;
+    let mut inv = Polynomial::constant(c0);
+    let two = Polynomial::constant(F::from_usize(2));
+    for _ in 0..bound {
+        inv = truncate(
+            &inv.mul_poly(&two.sub(&truncate(&lc.mul_poly(&inv), k, bound))),
+            k,
+            bound,
+        );
+    }
+    let mut target = truncate(&v.mul_poly(&inv), k, bound);
+    let mut ans = Vec::with_capacity(factors.len());
+    for i in 0..factors.len() - 1 {
+        let rest = factors[i + 1..]
+            .iter()
+            .fold(Univariate::constant(F::unit()), |acc, g| acc.mul(g));
+        let (g, h) = lift_two(&target, &factors[i], &rest, k, bound);
+        ans.push(g);
+        target = h;
+    }
+    ans.push(target);
+    ans
+}
+
+// lift_two
+// target ≡ g0 h0 (x_k以外の変数を0としたとき) を, x_k以外の変数についての次数boundまで持ち上げる.
+// s g0 + t h0 = 1 を用いて, 次数jの誤差の各係数cを σ g0 + τ h0 = c と分けて g, h を修正する.
+fn lift_two<F, const N: usize>(
+    target: &Polynomial<F, N>,
+    g0: &Univariate<F>,
+    h0: &Univariate<F>,
+    k: usize,
+    bound: u32,
+) -> (Polynomial<F, N>, Polynomial<F, N>)
+where
+    F: field::Field,
+{
+    let (_, s, t) = g0.ext_gcd(h0);
+    let mut g = embed(g0, k, [0; N]);
+    let mut h = embed(h0, k, [0; N]);
+    for j in 1..=bound {
+        let error = truncate(&target.sub(&g.mul_poly(&h)), k, bound);
+        for (alpha, c) in coefficients_in(&error, k) {
+            if alpha.iter().sum::<u32>() != j {
+                continue;
+            }
+            let (q, tau) = c.mul(&t).div_rem(g0);
+            let sigma = c.mul(&s).add(&q.mul(h0));
+            g = g.add(&embed(&tau, k, alpha));
+            h = h.add(&embed(&sigma, k, alpha));
+        }
+    }
+    (g, h)
+}
+
+// lead_coef
+// x_kについての先頭係数を, x_kを含まないN変数多項式として返す.
+fn lead_coef<F, const N: usize>(poly: &Polynomial<F, N>, k: usize) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    let degree = poly
+        .monos
+        .iter()
+        .map(|mono| mono.degree[k])
+        .max()
+        .unwrap_or(0);
+    Polynomial::new(
+        poly.monos
+            .iter()
+            .filter(|mono| mono.degree[k] == degree)
+            .map(|mono| {
+                let mut degree = mono.degree;
+                degree[k] = 0;
+                Monomial::new(mono.coef, degree)
+            })
+            .collect(),
+    )
+}
+
+// truncate
+// x_k以外の変数についての全次数がboundを超える単項式を取り除く.
+fn truncate<F, const N: usize>(poly: &Polynomial<F, N>, k: usize, bound: u32) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    Polynomial::new(
+        poly.monos
+            .iter()
+            .filter(|mono| mono.total_degree() - mono.degree[k] <= bound)
+            .cloned()
+            .collect(),
+    )
+}
+
+// translate
+// 各変数x_iをx_i + shift[i]に置き換えた多項式を返す.
+fn translate<F, const N: usize>(poly: &Polynomial<F, N>, shift: &[F; N]) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    let polys: [Polynomial<F, N>; N] =
+        std::array::from_fn(|i| Polynomial::variable(i).add(&Polynomial::constant(shift[i])));
+    poly.substitute(&polys)
+}
+
+fn monic<F, const N: usize>(poly: &Polynomial<F, N>) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    let mut poly = poly.clone();
+    poly.norm();
+    poly
+}
@@ -0,0 +1,129 @@
+use crate::algebra::field;
+use crate::polynomial::gcd::{self, Gcd};
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::Univariate;
+use std::collections::BTreeMap;
+
+// squarefree_univariate
+// 1変数多項式の無平方分解. f = lc * Π g^e となるモニックで無平方かつ互いに素なgと重複度eの組を,
+// 重複度の小さいものから返す.
+// 標数pでは微分が0になる因子, すなわちp乗の因子があるので, 残った部分のp乗根をとって再帰する.
+pub fn squarefree_univariate<F>(f: &Univariate<F>) -> Vec<(Univariate<F>, usize)>
+where
+    F: field::Field,
+{
+    let mut ans = Vec::new();
+    if f.degree() == 0 {
+        return ans;
+    }
+    let f = f.monic();
+    let mut c = f.gcd(&f.derivative());
+    let mut w = f.div_rem(&c).0;
+    let mut i = 1;
+    while w.degree() > 0 {
+        let y = w.gcd(&c);
+        let z = w.div_rem(&y).0;
+        if z.degree() > 0 {
+            ans.push((z, i));
+        }
+        i += 1;
+        w = y;
+        c = c.div_rem(&w).0;
+    }
+    if c.degree() > 0 {
+        // 標数0ではここに来ない. cはp乗なので, 係数を取り出してp乗根をとる.
+        let p = F::characteristic();
+        let root = Univariate::new(c.coefs.iter().step_by(p).copied().collect());
+        for (g, e) in squarefree_univariate(&root) {
+            ans.push((g, e * p));
+        }
+    }
+    ans.sort_by_key(|(_, e)| *e);
+    ans
+}
+
+// squarefree
+// N変数多項式の無平方分解. f = lc * Π g^e となる先頭係数が1で無平方かつ互いに素なgと重複度eの組を,
+// 重複度の小さいものから返す.
+// ある変数x_kについての微分を使うと, x_kを含まない因子と重複度がpの倍数の因子は分解されずに残るので,
+// 残った部分を別の変数で分解する. どの変数で微分しても0ならp乗なので, p乗根をとって再帰する.
+// 多変数の最大公約数を使うため M = N+1 とする. 最大公約数が求められない場合はエラーを返す.
+pub fn squarefree<F, const N: usize, const M: usize>(
+    f: &Polynomial<F, N>,
+) -> Result<Vec<(Polynomial<F, N>, usize)>, &'static str>
+where
+    F: Gcd,
+{
+    let mut parts = BTreeMap::new();
+    if !f.is_zero() {
+        decompose::<F, N, M>(f.clone(), 1, &mut parts)?;
+    }
+    Ok(parts
+        .into_iter()
+        .map(|(e, mut g): (usize, Polynomial<F, N>)| {
+            g.norm();
+            (g, e)
+        })
+        .collect())
+}
+
+// squarefree_part
+// 無平方部分, すなわちfの相異なる既約因子すべての積を先頭係数を1にして返す. M = N+1 とする.
+pub fn squarefree_part<F, const N: usize, const M: usize>(
+    f: &Polynomial<F, N>,
+) -> Result<Polynomial<F, N>, &'static str>
+where
+    F: Gcd,
+{
+    Ok(squarefree::<F, N, M>(f)?
+        .iter()
+        .fold(Polynomial::constant(F::unit()), |acc, (g, _)| {
+            acc.mul_poly(g)
+        }))
+}
+
+// decompose
+// fを分解し, 重複度e*multiplicityの部分をpartsの対応する多項式にかける.
+fn decompose<F, const N: usize, const M: usize>(
+    mut f: Polynomial<F, N>,
+    multiplicity: usize,
+    parts: &mut BTreeMap<usize, Polynomial<F, N>>,
+) -> Result<(), &'static str>
+where
+    F: Gcd,
+{
+    while f.total_degree() > 0 {
+        let k = match (0..N).find(|&k| !f.derivative(k).is_zero()) {
+            Some(k) => k,
+            None => {
+                let p = F::characteristic() as u32;
+                let root = Polynomial::new(
+                    f.monos
+                        .iter()
+                        .map(|mono| Monomial::new(mono.coef, mono.degree.map(|deg| deg / p)))
+                        .collect(),
+                );
+                return decompose::<F, N, M>(root, multiplicity * p as usize, parts);
+            }
+        };
+        let mut c = gcd::gcd::<F, N, M>(&f, &f.derivative(k))?;
+        let mut w = f.div_exact(&c).unwrap();
+        let mut i = 1;
+        while w.total_degree() > 0 {
+            let y = gcd::gcd::<F, N, M>(&w, &c)?;
+            let z = w.div_exact(&y).unwrap();
+            if z.total_degree() > 0 {
+                let part = parts
+                    .entry(i * multiplicity)
+                    .or_insert_with(|| Polynomial::constant(F::unit()));
+                *part = part.mul_poly(&z);
+            }
+            i += 1;
+            c = c.div_exact(&y).unwrap();
+            w = y;
+        }
+        f = c;
+    }
+    Ok(())
+}
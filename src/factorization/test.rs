@@ -0,0 +1,268 @@
+use super::multivariate;
+use super::squarefree;
+use super::squarefree::{squarefree_part, squarefree_univariate};
+use super::univariate::{factor, is_irreducible, squarefree};
+use crate::algebra::fp;
+use crate::algebra::rational::Rational;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::Univariate;
+
+type Fp = fp::Fp<5>;
+
+fn u(coefs: &[i32]) -> Univariate<Fp> {
+    Univariate::new(coefs.iter().map(|&c| Fp::new(c)).collect())
+}
+
+fn expand(lc: Fp, factors: &[(Univariate<Fp>, usize)]) -> Univariate<Fp> {
+    let mut ans = Univariate::constant(lc);
+    for (g, e) in factors.iter() {
+        for _ in 0..*e {
+            ans = ans.mul(g);
+        }
+    }
+    ans
+}
+
+#[test]
+fn univariate_factor() {
+    // 3 (x + 1)^2 (x^2 + 2) (x^2 + x + 2) (x + 3) (x^4 + x^3 + 1) over F_5
+    let f = u(&[3])
+        .mul(&u(&[1, 1]))
+        .mul(&u(&[1, 1]))
+        .mul(&u(&[2, 0, 1]))
+        .mul(&u(&[2, 1, 1]))
+        .mul(&u(&[3, 1]))
+        .mul(&u(&[1, 0, 0, 1, 1]));
+    let (lc, factors) = factor(&f);
+    assert_eq!(lc, Fp::new(3));
+    assert_eq!(expand(lc, &factors), f);
+    assert!(factors.iter().all(|(g, _)| is_irreducible(g)));
+    // x^4 + x^3 + 1 = (x + 3)(x^3 + 3x^2 + x + 2)
+    assert_eq!(
+        factors
+            .iter()
+            .map(|(g, e)| (g.degree(), *e))
+            .collect::<Vec<_>>(),
+        vec![(1, 2), (1, 2), (2, 1), (2, 1), (3, 1)]
+    );
+    assert!(is_irreducible(&u(&[2, 0, 1])));
+    assert!(!is_irreducible(&u(&[4, 0, 1])));
+}
+
+#[test]
+fn positive_characteristic() {
+    // (x^5 + 1)(x + 2)^3 = (x + 1)^5 (x + 2)^3 over F_5: 微分で消える因子を含む
+    let f = u(&[1, 0, 0, 0, 0, 1])
+        .mul(&u(&[2, 1]))
+        .mul(&u(&[2, 1]))
+        .mul(&u(&[2, 1]));
+    let mut sqf = squarefree(&f);
+    sqf.sort_by_key(|(_, e)| *e);
+    assert_eq!(sqf, vec![(u(&[2, 1]), 3), (u(&[1, 1]), 5)]);
+    let (_, factors) = factor(&f);
+    assert_eq!(factors.len(), 2);
+
+    // F_2 上の x^4 + x + 1 は既約, x^4 + x^2 + 1 = (x^2 + x + 1)^2
+    let v = |coefs: &[i32]| Univariate::new(coefs.iter().map(|&c| fp::Fp::<2>::new(c)).collect());
+    assert!(is_irreducible(&v(&[1, 1, 0, 0, 1])));
+    assert_eq!(factor(&v(&[1, 0, 1, 0, 1])).1, vec![(v(&[1, 1, 1]), 2)]);
+    // x^3 + x = x (x + 1)^2, x^6 + x^5 + x^4 + x^3 + x^2 + x + 1 は 2つの3次既約多項式の積
+    let (_, factors) = factor(&v(&[1, 1, 1, 1, 1, 1, 1]));
+    assert_eq!(factors.len(), 2);
+    assert!(factors.iter().all(|(g, e)| g.degree() == 3 && *e == 1));
+}
+
+fn assert_factors<const P: i32, const N: usize, const M: usize>(
+    f: &Polynomial<fp::Fp<P>, N>,
+    expected: &[(Polynomial<fp::Fp<P>, N>, usize)],
+) {
+    let (lc, factors) = multivariate::factor::<P, N, M>(f).unwrap();
+    assert_eq!(factors.len(), expected.len());
+    for (g, e) in expected.iter() {
+        let mut g = g.clone();
+        g.norm();
+        assert!(factors.contains(&(g, *e)));
+    }
+    let product = factors
+        .iter()
+        .fold(Polynomial::constant(lc), |acc, (g, e)| {
+            (0..*e).fold(acc, |acc, _| acc.mul_poly(g))
+        });
+    assert_eq!(&product, f);
+}
+
+#[test]
+fn multivariate_factor() {
+    let x = Polynomial::<Fp, 2>::variable(0);
+    let y = Polynomial::<Fp, 2>::variable(1);
+    let c = |c| Polynomial::<Fp, 2>::constant(Fp::new(c));
+    // 2 (x + y + 1)^2 (xy + 2)(y + 3)(x^2 + y^2 + 2)
+    let g1 = x.add(&y).add(&c(1));
+    let g2 = x.mul_poly(&y).add(&c(2));
+    let g3 = y.add(&c(3));
+    let g4 = x.mul_poly(&x).add(&y.mul_poly(&y)).add(&c(2));
+    let f = c(2)
+        .mul_poly(&g1)
+        .mul_poly(&g1)
+        .mul_poly(&g2)
+        .mul_poly(&g3)
+        .mul_poly(&g4);
+    assert_factors::<5, 2, 3>(&f, &[(g1, 2), (g2, 1), (g3, 1), (g4, 1)]);
+
+    // (x_1 x_2 + x_3)(x_1 + x_2 x_3 + 1)
+    let v = |i| Polynomial::<Fp, 3>::variable(i);
+    let h1 = v(0).mul_poly(&v(1)).add(&v(2));
+    let h2 = v(0)
+        .add(&v(1).mul_poly(&v(2)))
+        .add(&Polynomial::constant(Fp::new(1)));
+    assert_factors::<5, 3, 4>(&h1.mul_poly(&h2), &[(h1, 1), (h2, 1)]);
+}
+
+#[test]
+fn multivariate_positive_characteristic() {
+    // F_3 上で x^3 y^3 + y^6 = y^3 (x + y)^3 と (x^3 + y)(x + y)^2
+    type F3 = fp::Fp<3>;
+    let x = Polynomial::<F3, 2>::variable(0);
+    let y = Polynomial::<F3, 2>::variable(1);
+    let x_plus_y = x.add(&y);
+    let cube = |f: &Polynomial<F3, 2>| f.mul_poly(f).mul_poly(f);
+    let f = cube(&y).mul_poly(&cube(&x_plus_y));
+    assert_factors::<3, 2, 3>(&f, &[(y.clone(), 3), (x_plus_y.clone(), 3)]);
+    let g = cube(&x).add(&y);
+    let f = g.mul_poly(&x_plus_y).mul_poly(&x_plus_y);
+    assert_factors::<3, 2, 3>(&f, &[(g, 1), (x_plus_y, 2)]);
+}
+
+#[test]
+fn multivariate_rational() {
+    let q = |num, den| Rational::new(num, den);
+    let check = |f: &Polynomial<Rational, 2>, expected: &[(Polynomial<Rational, 2>, usize)]| {
+        let (lc, factors) = multivariate::factor_rational::<2, 3>(f).unwrap();
+        assert_eq!(factors.len(), expected.len());
+        for (g, e) in expected.iter() {
+            let mut g = g.clone();
+            g.norm();
+            assert!(factors.contains(&(g, *e)));
+        }
+        let product = factors
+            .iter()
+            .fold(Polynomial::constant(lc), |acc, (g, e)| {
+                (0..*e).fold(acc, |acc, _| acc.mul_poly(g))
+            });
+        assert_eq!(&product, f);
+    };
+    let x = Polynomial::<Rational, 2>::variable(0);
+    let y = Polynomial::<Rational, 2>::variable(1);
+    let c = |num, den| Polynomial::<Rational, 2>::constant(q(num, den));
+    // x^4 + 1 と x^2 + y^2 はQ上既約だが, F_pでは x^4 + 1 は常に分解する.
+    let x4 = x.mul_poly(&x).mul_poly(&x).mul_poly(&x);
+    check(&x4.add(&c(1, 1)), &[(x4.add(&c(1, 1)), 1)]);
+    let g = x.mul_poly(&x).add(&y.mul_poly(&y));
+    check(&g, &[(g.clone(), 1)]);
+    // x^4 - 4 = (x^2 - 2)(x^2 + 2)
+    let x2 = x.mul_poly(&x);
+    check(
+        &x4.sub(&c(4, 1)),
+        &[(x2.sub(&c(2, 1)), 1), (x2.add(&c(2, 1)), 1)],
+    );
+    // 3/2 (x + y/2)^2 (x^2 y - 3y + 1/3)(xy + x + 7) y
+    let g1 = x.add(&c(1, 2).mul_poly(&y));
+    let g2 = x2.mul_poly(&y).sub(&c(3, 1).mul_poly(&y)).add(&c(1, 3));
+    let g3 = x.mul_poly(&y).add(&x).add(&c(7, 1));
+    let f = c(3, 2)
+        .mul_poly(&g1)
+        .mul_poly(&g1)
+        .mul_poly(&g2)
+        .mul_poly(&g3)
+        .mul_poly(&y);
+    check(&f, &[(g1, 2), (g2, 1), (g3, 1), (y.clone(), 1)]);
+
+    // (x^d + y + 1)(y^d + x - 1) のクロネッカー代入の像の次数は (d + 1)(d + 2) なので,
+    // d = 6 では持ち上げられるが d = 7 では法p^kが大きくなりすぎる.
+    let pow = |f: &Polynomial<Rational, 2>, d| (0..d).fold(c(1, 1), |acc, _| acc.mul_poly(f));
+    let g1 = pow(&x, 6).add(&y).add(&c(1, 1));
+    let g2 = pow(&y, 6).add(&x).sub(&c(1, 1));
+    check(&g1.mul_poly(&g2), &[(g1, 1), (g2, 1)]);
+    let f = pow(&x, 7)
+        .add(&y)
+        .add(&c(1, 1))
+        .mul_poly(&pow(&y, 7).add(&x).sub(&c(1, 1)));
+    assert_eq!(
+        multivariate::factor_rational::<2, 3>(&f),
+        Err("coefficients are too large to lift")
+    );
+}
+
+#[test]
+fn roots() {
+    use super::multivariate::Factorize;
+    // (x - 1)^2 (x - 3)(x^2 + 2) のF_5における根は1, 3, Qにおける根は1, 3
+    let f = u(&[-1, 1])
+        .mul(&u(&[-1, 1]))
+        .mul(&u(&[-3, 1]))
+        .mul(&u(&[2, 0, 1]));
+    let mut r = Fp::roots(&f).unwrap();
+    r.sort_by_key(|c| c.val());
+    assert_eq!(r, vec![Fp::new(1), Fp::new(3)]);
+    let q = |coefs: &[i64]| Univariate::new(coefs.iter().map(|&c| Rational::new(c, 1)).collect());
+    let f = q(&[-1, 1])
+        .mul(&q(&[-1, 1]))
+        .mul(&q(&[-3, 1]))
+        .mul(&q(&[2, 0, 1]));
+    let r = Rational::roots(&f).unwrap();
+    assert_eq!(r.len(), 2);
+    assert!(r.contains(&Rational::new(1, 1)) && r.contains(&Rational::new(3, 1)));
+    assert_eq!(Rational::roots(&q(&[2, 0, 1])), Ok(vec![]));
+}
+
+#[test]
+fn squarefree_decomposition() {
+    // Q上で (x + 1)^2 (x - 2)
+    let q = |coefs: &[i64]| Univariate::new(coefs.iter().map(|&c| Rational::new(c, 1)).collect());
+    let f = q(&[1, 1])
+        .mul(&q(&[1, 1]))
+        .mul(&q(&[-2, 1]))
+        .scale(Rational::new(3, 2));
+    assert_eq!(
+        squarefree_univariate(&f),
+        vec![(q(&[-2, 1]), 1), (q(&[1, 1]), 2)]
+    );
+
+    // F_3上で (x^3 + y)(xy + 1)^2 (x + y)^3 y^4
+    type F3 = fp::Fp<3>;
+    let x = Polynomial::<F3, 2>::variable(0);
+    let y = Polynomial::<F3, 2>::variable(1);
+    let one = Polynomial::constant(F3::new(1));
+    let g1 = x.mul_poly(&x).mul_poly(&x).add(&y);
+    let g2 = x.mul_poly(&y).add(&one);
+    let g3 = x.add(&y);
+    let pow = |f: &Polynomial<F3, 2>, e| (0..e).fold(one.clone(), |acc, _| acc.mul_poly(f));
+    let f = g1
+        .mul_poly(&pow(&g2, 2))
+        .mul_poly(&pow(&g3, 3))
+        .mul_poly(&pow(&y, 4));
+    assert_eq!(
+        squarefree::squarefree::<F3, 2, 3>(&f).unwrap(),
+        vec![
+            (g1.clone(), 1),
+            (g2.clone(), 2),
+            (g3.clone(), 3),
+            (y.clone(), 4)
+        ]
+    );
+    assert_eq!(
+        squarefree_part::<F3, 2, 3>(&f).unwrap(),
+        g1.mul_poly(&g2).mul_poly(&g3).mul_poly(&y)
+    );
+
+    // Q上で (x^2 + y)^2 (x - y)
+    let x = Polynomial::<Rational, 2>::variable(0);
+    let y = Polynomial::<Rational, 2>::variable(1);
+    let h1 = x.sub(&y);
+    let h2 = x.mul_poly(&x).add(&y);
+    let f = h1.mul_poly(&h2).mul_poly(&h2);
+    assert_eq!(
+        squarefree::squarefree::<Rational, 2, 3>(&f).unwrap(),
+        vec![(h1, 1), (h2, 2)]
+    );
+}
@@ -0,0 +1,140 @@
+use super::squarefree;
+use crate::algebra::field::Field;
+use crate::algebra::fp::Fp;
+use crate::polynomial::univariate::Univariate;
+
+// factor
+// F_P上の1変数多項式を既約分解する. 先頭係数と, モニックな既約因子とその重複度の組を返す.
+// 無平方分解, 次数別分解, 等次数分解(Cantor–Zassenhaus)の順に行う. 因子は次数の小さいものから並ぶ.
+pub fn factor<const P: i32>(f: &Univariate<Fp<P>>) -> (Fp<P>, Vec<(Univariate<Fp<P>>, usize)>) {
+    let mut factors = Vec::new();
+    for (g, e) in squarefree(f) {
+        for (h, d) in distinct_degree(&g) {
+            for k in equal_degree(&h, d) {
+                factors.push((k, e));
+            }
+        }
+    }
+    factors.sort_by_key(|(g, _)| g.degree());
+    (f.lead(), factors)
+}
+
+// is_irreducible
+// F_P上で既約か判定する. 定数は既約でないとする.
+pub fn is_irreducible<const P: i32>(f: &Univariate<Fp<P>>) -> bool {
+    if f.degree() == 0 {
+        return false;
+    }
+    let sqf = squarefree(f);
+    if sqf.len() != 1 || sqf[0].1 != 1 {
+        return false;
+    }
+    let ddf = distinct_degree(&sqf[0].0);
+    ddf.len() == 1 && ddf[0].1 == f.degree()
+}
+
+// squarefree
+// 無平方分解. f = lc * Π g^e となるモニックで無平方かつ互いに素なgと重複度eの組を返す.
+pub fn squarefree<const P: i32>(f: &Univariate<Fp<P>>) -> Vec<(Univariate<Fp<P>>, usize)> {
+    squarefree::squarefree_univariate(f)
+}
+
+// distinct_degree
+// 無平方でモニックなfを次数別分解する. 次数dの既約因子すべての積とdの組を返す.
+// x^{P^d} - x が次数がdを割り切る既約多項式すべての積であることを用いる.
+pub fn distinct_degree<const P: i32>(f: &Univariate<Fp<P>>) -> Vec<(Univariate<Fp<P>>, usize)> {
+    let mut ans = Vec::new();
+    let mut f = f.monic();
+    let x = Univariate::x();
+    let mut h = x.clone();
+    let mut d = 1;
+    while f.degree() >= 2 * d {
+        h = h.pow_mod(P as usize, &f);
+        let g = f.gcd(&h.sub(&x));
+        if g.degree() > 0 {
+            f = f.div_rem(&g).0;
+            h = h.div_rem(&f).1;
+            ans.push((g, d));
+        }
+        d += 1;
+    }
+    if f.degree() > 0 {
+        let d = f.degree();
+        ans.push((f, d));
+    }
+    ans
+}
+
+// equal_degree
+// 次数dの相異なるモニックな既約多項式の積fを既約因子に分解する(Cantor–Zassenhaus).
+// 擬似乱数で選んだaについて, Pが奇数なら a^{(P^d-1)/2} - 1, P = 2 ならトレース Σ a^{2^k} とfの
+// 最大公約数をとり, 自明でない因子が得られるまで繰り返す.
+pub fn equal_degree<const P: i32>(f: &Univariate<Fp<P>>, d: usize) -> Vec<Univariate<Fp<P>>> {
+    let mut ans = Vec::new();
+    let mut rng = Xorshift(0x2545_f491_4f6c_dd1d);
+    split(f.monic(), d, &mut rng, &mut ans);
+    ans
+}
+
+fn split<const P: i32>(
+    f: Univariate<Fp<P>>,
+    d: usize,
+    rng: &mut Xorshift,
+    ans: &mut Vec<Univariate<Fp<P>>>,
+) {
+    if f.degree() <= d {
+        if f.degree() > 0 {
+            ans.push(f);
+        }
+        return;
+    }
+    loop {
+        let a = Univariate::new(
+            (0..f.degree())
+                .map(|_| Fp::new((rng.next() % P as u64) as i32))
+                .collect(),
+        );
+        if a.degree() == 0 {
+            continue;
+        }
+        let b = if P == 2 {
+            // トレース a + a^2 + ... + a^{2^{d-1}}
+            let mut s = a.clone();
+            let mut t = a.clone();
+            for _ in 1..d {
+                s = s.mul(&s).div_rem(&f).1;
+                t = t.add(&s);
+            }
+            t
+        } else {
+            // (P^d-1)/2 = (P-1)/2 * (1 + P + ... + P^{d-1}) なので, ノルム a a^P ... a^{P^{d-1}} を
+            // (P-1)/2乗すればよい.
+            let mut s = a.clone();
+            let mut norm = a.clone();
+            for _ in 1..d {
+                s = s.pow_mod(P as usize, &f);
+                norm = norm.mul(&s).div_rem(&f).1;
+            }
+            norm.pow_mod((P as usize - 1) / 2, &f)
+                .sub(&Univariate::constant(Fp::unit()))
+        };
+        let g = f.gcd(&b);
+        if g.degree() > 0 && g.degree() < f.degree() {
+            let rest = f.div_rem(&g).0;
+            split(g, d, rng, ans);
+            split(rest, d, rng, ans);
+            return;
+        }
+    }
+}
+
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
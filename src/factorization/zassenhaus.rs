@@ -0,0 +1,307 @@
+use super::univariate as uni;
+use crate::algebra::combination::subsets;
+use crate::algebra::fp::Fp;
+use crate::algebra::gcd;
+use crate::polynomial::univariate::Univariate;
+
+// 持ち上げる法p^kの上限. 係数の積がi128に収まるようにする.
+const MAX_MODULUS: i128 = 1 << 62;
+
+// IntegerPolynomial
+// 整数係数の1変数多項式. coefs[i]はx^iの係数で, 最高次の係数は0でない.
+pub type IntegerPolynomial = Vec<i128>;
+
+// factor_integer
+// 無平方で原始的な整数係数の1変数多項式を, Z上の既約因子(原始的)に分解する(ザッセンハウスのアルゴリズム).
+// 先頭係数を割らず像が無平方のままになる素数pで分解し, ヘンゼル持ち上げで法p^kの分解にしてから,
+// 因子の部分集合の積を試して真の因子を組み立てる. p^kは因子の係数の上限(ミニョットの評価)の2倍より大きくとる.
+// 因子の符号は定めない. 適当な素数がない場合や, p^kが大きくなりすぎる場合はエラーを返す.
+pub fn factor_integer(f: &[i128]) -> Result<Vec<IntegerPolynomial>, &'static str> {
+    if f.len() <= 2 {
+        return Ok(vec![f.to_vec()]);
+    }
+    type Attempt = fn(&[i128]) -> Option<Result<Vec<IntegerPolynomial>, &'static str>>;
+    let attempts: [Attempt; 25] = [
+        factor_with::<3>,
+        factor_with::<5>,
+        factor_with::<7>,
+        factor_with::<11>,
+        factor_with::<13>,
+        factor_with::<17>,
+        factor_with::<19>,
+        factor_with::<23>,
+        factor_with::<29>,
+        factor_with::<31>,
+        factor_with::<37>,
+        factor_with::<41>,
+        factor_with::<43>,
+        factor_with::<47>,
+        factor_with::<53>,
+        factor_with::<59>,
+        factor_with::<61>,
+        factor_with::<67>,
+        factor_with::<71>,
+        factor_with::<73>,
+        factor_with::<79>,
+        factor_with::<83>,
+        factor_with::<89>,
+        factor_with::<97>,
+        factor_with::<101>,
+    ];
+    attempts
+        .iter()
+        .find_map(|attempt| attempt(f))
+        .unwrap_or(Err("no prime keeps the polynomial squarefree"))
+}
+
+// factor_with
+// 素数Pを用いて分解する. Pが先頭係数を割るか, 法Pでの像が無平方でない場合はNoneを返す.
+fn factor_with<const P: i32>(f: &[i128]) -> Option<Result<Vec<IntegerPolynomial>, &'static str>> {
+    let p = P as i128;
+    let lc = *f.last().unwrap();
+    if lc % p == 0 {
+        return None;
+    }
+    let image = reduce::<P>(f);
+    if image.gcd(&image.derivative()).degree() > 0 {
+        return None;
+    }
+    let (_, image_factors) = uni::factor(&image);
+    if image_factors.len() == 1 {
+        return Some(Ok(vec![f.to_vec()]));
+    }
+    let modulus = match lifting_modulus(f, p) {
+        Some(modulus) => modulus,
+        None => return Some(Err("coefficients are too large to lift")),
+    };
+    let image_factors: Vec<Univariate<Fp<P>>> = image_factors.into_iter().map(|(g, _)| g).collect();
+    let lifted = hensel_lift::<P>(f, &image_factors, modulus);
+    Some(Ok(recombine(f.to_vec(), lifted, modulus)))
+}
+
+// lifting_modulus
+// 因子の係数の絶対値の上限 B = 2^n ||f||_2 について, 2|lc|B を超えるpのべきを返す.
+// MAX_MODULUSを超える場合はNoneを返す.
+fn lifting_modulus(f: &[i128], p: i128) -> Option<i128> {
+    let n = f.len() as u32 - 1;
+    let norm = f
+        .iter()
+        .map(|&c| (c as f64) * (c as f64))
+        .sum::<f64>()
+        .sqrt()
+        .ceil();
+    if n >= 62 || norm >= MAX_MODULUS as f64 {
+        return None;
+    }
+    let bound = (norm as i128)
+        .checked_mul(1 << n)?
+        .checked_mul(2 * f.last().unwrap().abs())?;
+    let mut modulus = p;
+    while modulus <= bound {
+        modulus *= p;
+        if modulus >= MAX_MODULUS {
+            return None;
+        }
+    }
+    Some(modulus)
+}
+
+// hensel_lift
+// f ≡ lc Π g_i (mod p) となるモニックで互いに素なg_iを, 法modulusでのモニックな因子に持ち上げる.
+// multivariate::hensel_lift と同じく, 1つずつ残りの積と2つに分けて持ち上げる.
+fn hensel_lift<const P: i32>(
+    f: &[i128],
+    factors: &[Univariate<Fp<P>>],
+    modulus: i128,
+) -> Vec<IntegerPolynomial> {
+    let inv = inverse_mod(*f.last().unwrap(), modulus);
+    let mut target: IntegerPolynomial = f.iter().map(|&c| mul_mod(c, inv, modulus)).collect();
+    let mut ans = Vec::with_capacity(factors.len());
+    for i in 0..factors.len() - 1 {
+        let rest = factors[i + 1..]
+            .iter()
+            .fold(Univariate::constant(Fp::new(1)), |acc, g| acc.mul(g));
+        let (g, h) = lift_two::<P>(&target, &factors[i], &rest, modulus);
+        ans.push(g);
+        target = h;
+    }
+    ans.push(target);
+    ans
+}
+
+// lift_two
+// target ≡ g0 h0 (mod p) を法modulusまで1桁ずつ持ち上げる.
+// 法p^jで target ≡ gh のとき, 誤差を p^j c とおいて σ g0 + τ h0 ≡ c (mod p) と分け, g, h を修正する.
+fn lift_two<const P: i32>(
+    target: &[i128],
+    g0: &Univariate<Fp<P>>,
+    h0: &Univariate<Fp<P>>,
+    modulus: i128,
+) -> (IntegerPolynomial, IntegerPolynomial) {
+    let p = P as i128;
+    let (_, s, t) = g0.ext_gcd(h0);
+    let mut g: IntegerPolynomial = g0.coefs.iter().map(|c| c.val() as i128).collect();
+    let mut h: IntegerPolynomial = h0.coefs.iter().map(|c| c.val() as i128).collect();
+    let mut power = p;
+    while power < modulus {
+        let product = mul(&g, &h, modulus);
+        let error = Univariate::new(
+            (0..target.len())
+                .map(|i| {
+                    let e = (target[i] - product.get(i).copied().unwrap_or(0)).rem_euclid(modulus);
+                    Fp::new((e / power % p) as i32)
+                })
+                .collect(),
+        );
+        let (q, tau) = error.mul(&t).div_rem(g0);
+        let sigma = error.mul(&s).add(&q.mul(h0));
+        add_scaled(&mut g, &tau, power, modulus);
+        add_scaled(&mut h, &sigma, power, modulus);
+        power *= p;
+    }
+    (g, h)
+}
+
+// recombine
+// 法modulusでのモニックな因子の部分集合を小さいものから試し, lc Π g を対称な剰余で表した原始的な部分が
+// fを割り切れば真の因子として取り除く.
+fn recombine(
+    mut f: IntegerPolynomial,
+    mut lifted: Vec<IntegerPolynomial>,
+    modulus: i128,
+) -> Vec<IntegerPolynomial> {
+    let mut ans = Vec::new();
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let lc = *f.last().unwrap();
+        let found = subsets(lifted.len(), size).into_iter().find_map(|subset| {
+            let product = subset.iter().fold(vec![lc.rem_euclid(modulus)], |acc, &i| {
+                mul(&acc, &lifted[i], modulus)
+            });
+            let candidate = primitive_part(
+                &product
+                    .iter()
+                    .map(|&c| if 2 * c > modulus { c - modulus } else { c })
+                    .collect::<Vec<_>>(),
+            );
+            div_exact(&f, &candidate).map(|quo| (subset, candidate, quo))
+        });
+        match found {
+            Some((subset, candidate, quo)) => {
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                }
+                ans.push(candidate);
+                f = quo;
+            }
+            None => size += 1,
+        }
+    }
+    if f.len() > 1 {
+        ans.push(f);
+    }
+    ans
+}
+
+// div_exact
+// 整数係数の多項式としてgで割り切れる場合は商を返す. 途中で割り切れない係数が出るか溢れる場合はNoneを返す.
+pub fn div_exact(f: &[i128], g: &[i128]) -> Option<IntegerPolynomial> {
+    if f.len() < g.len() {
+        return None;
+    }
+    let lc = *g.last().unwrap();
+    let mut rem = f.to_vec();
+    let mut quo = vec![0; f.len() - g.len() + 1];
+    for i in (0..quo.len()).rev() {
+        let top = rem[i + g.len() - 1];
+        if top % lc != 0 {
+            return None;
+        }
+        let c = top / lc;
+        quo[i] = c;
+        for (j, &b) in g.iter().enumerate() {
+            rem[i + j] = rem[i + j].checked_sub(c.checked_mul(b)?)?;
+        }
+    }
+    if rem.iter().all(|&c| c == 0) {
+        Some(quo)
+    } else {
+        None
+    }
+}
+
+// mul_exact
+// 整数係数の多項式の積を返す. 溢れる場合はNoneを返す.
+pub fn mul_exact(f: &[i128], g: &[i128]) -> Option<IntegerPolynomial> {
+    let mut ans = vec![0i128; f.len() + g.len() - 1];
+    for (i, &a) in f.iter().enumerate() {
+        for (j, &b) in g.iter().enumerate() {
+            ans[i + j] = ans[i + j].checked_add(a.checked_mul(b)?)?;
+        }
+    }
+    Some(ans)
+}
+
+// primitive_part
+// 係数の最大公約数で割り, 先頭係数を正にしたものを返す.
+pub fn primitive_part(f: &[i128]) -> IntegerPolynomial {
+    let mut f = f.to_vec();
+    while f.len() > 1 && *f.last().unwrap() == 0 {
+        f.pop();
+    }
+    let content = f.iter().fold(0, |acc, &c| gcd::gcd(acc, c)) * f.last().unwrap().signum();
+    f.iter().map(|&c| c / content).collect()
+}
+
+fn reduce<const P: i32>(f: &[i128]) -> Univariate<Fp<P>> {
+    Univariate::new(
+        f.iter()
+            .map(|&c| Fp::new(c.rem_euclid(P as i128) as i32))
+            .collect(),
+    )
+}
+
+// mul
+// 法modulusでの積を返す. 係数は0以上modulus未満とする.
+fn mul(f: &[i128], g: &[i128], modulus: i128) -> IntegerPolynomial {
+    let mut ans = vec![0; f.len() + g.len() - 1];
+    for (i, &a) in f.iter().enumerate() {
+        for (j, &b) in g.iter().enumerate() {
+            ans[i + j] = (ans[i + j] + mul_mod(a, b, modulus)) % modulus;
+        }
+    }
+    ans
+}
+
+// add_scaled
+// fにscale * uを加え, 法modulusで0以上の代表元にする.
+fn add_scaled<const P: i32>(
+    f: &mut IntegerPolynomial,
+    u: &Univariate<Fp<P>>,
+    scale: i128,
+    modulus: i128,
+) {
+    if f.len() < u.coefs.len() {
+        f.resize(u.coefs.len(), 0);
+    }
+    for (x, c) in f.iter_mut().zip(u.coefs.iter()) {
+        *x = (*x + c.val() as i128 * scale) % modulus;
+    }
+}
+
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    (a.rem_euclid(modulus) * b.rem_euclid(modulus)) % modulus
+}
+
+// inverse_mod
+// pと互いに素なaの, 法modulus(pのべき)での逆元を返す.
+fn inverse_mod(a: i128, modulus: i128) -> i128 {
+    let (mut r0, mut r1) = (modulus, a.rem_euclid(modulus));
+    let (mut s0, mut s1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    s0.rem_euclid(modulus)
+}
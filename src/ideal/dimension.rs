@@ -0,0 +1,73 @@
+use super::operation::Ideal;
+use crate::algebra::field;
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // is_zero_dimensional
+    // 0次元, すなわちV(I)が有限集合で空でないか判定する.
+    // すべての変数について, その変数のべきを先頭単項式に持つ元があれば0次元である.
+    pub fn is_zero_dimensional(&self) -> bool {
+        (0..N).all(|i| {
+            self.basis.iter().any(|f| {
+                let degree = f.monos[0].degree;
+                degree[i] > 0 && (0..N).all(|j| j == i || degree[j] == 0)
+            })
+        })
+    }
+
+    // is_independent
+    // 変数の集合vars(0から数える)が独立, すなわちvarsの変数のみからなる先頭単項式がないか判定する.
+    // このとき in(I) ∩ F[vars] = 0 なので, I ∩ F[vars] = 0 となる.
+    pub fn is_independent(&self, vars: &[usize]) -> bool {
+        self.basis.iter().all(|f| {
+            f.monos[0]
+                .degree
+                .iter()
+                .enumerate()
+                .any(|(i, &deg)| deg > 0 && !vars.contains(&i))
+        })
+    }
+
+    // maximal_independent_sets
+    // 包含関係について極大な独立集合をすべて返す. 各集合の変数は昇順に並ぶ.
+    // 多様体V(I)が空の場合は空のVecを返す.
+    pub fn maximal_independent_sets(&self) -> Vec<Vec<usize>> {
+        if self.is_unit() {
+            return vec![];
+        }
+        let independent: Vec<Vec<usize>> = (0..1usize << N)
+            .map(|mask| {
+                (0..N)
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .collect::<Vec<usize>>()
+            })
+            .filter(|vars| self.is_independent(vars))
+            .collect();
+        independent
+            .iter()
+            .filter(|vars| {
+                independent.iter().all(|other| {
+                    other.len() <= vars.len() || !vars.iter().all(|i| other.contains(i))
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    // independent_set
+    // 要素数が最大の独立集合を返す. その要素数がV(I)の次元になる. V(I)が空の場合はNoneを返す.
+    pub fn independent_set(&self) -> Option<Vec<usize>> {
+        self.maximal_independent_sets()
+            .into_iter()
+            .max_by_key(|vars| vars.len())
+    }
+
+    // dimension
+    // アフィン多様体V(I)の次元(R/Iのクルル次元)を返す. V(I)が空の場合はNoneを返す.
+    // 次元は先頭単項式のイデアルのみで決まり, 独立集合の要素数の最大値に等しい.
+    pub fn dimension(&self) -> Option<usize> {
+        self.independent_set().map(|vars| vars.len())
+    }
+}
@@ -0,0 +1,74 @@
+use super::operation::{eliminate_first, Ideal};
+use crate::algebra::field;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // eliminate
+    // varsに含まれる変数(0から数える)を消去した消去イデアル I ∩ k[残りの変数] を返す.
+    // varsの変数が先頭に来るように変数を並べ替えると辞書式順序が消去順序になるので,
+    // そのグレブナー基底のうちvarsの変数を含まない元を取り出して元の並びに戻す.
+    // varsに重複があってもよいが, 範囲外の添字は許さない.
+    pub fn eliminate(&self, vars: &[usize]) -> Ideal<F, N> {
+        assert!(vars.iter().all(|&i| i < N), "variable index out of range");
+        let mut vars = vars.to_vec();
+        vars.sort_unstable();
+        vars.dedup();
+        let mut order = vars.clone();
+        order.extend((0..N).filter(|i| !vars.contains(i)));
+        let mut perm = [0; N];
+        let mut inv = [0; N];
+        for (new, &old) in order.iter().enumerate() {
+            perm[old] = new;
+            inv[new] = old;
+        }
+        let k = vars.len();
+        let permuted = Ideal::new(self.basis.iter().map(|f| f.permute(&perm)).collect());
+        Ideal::new(
+            permuted
+                .basis
+                .iter()
+                .filter(|f| {
+                    f.monos
+                        .iter()
+                        .all(|mono| mono.degree[..k].iter().all(|&deg| deg == 0))
+                })
+                .map(|f| f.permute(&inv))
+                .collect(),
+        )
+    }
+}
+
+// implicitize
+// パラメータ表示 x_i = params[i](t_1, ..., t_K) の像のザリスキー閉包を定めるイデアルを返す.
+// t_1, ..., t_K, x_1, ..., x_N の M = K+N 変数で (x_i - params[i]) を考え, tを消去する.
+pub fn implicitize<F, const K: usize, const N: usize, const M: usize>(
+    params: &[Polynomial<F, K>; N],
+) -> Ideal<F, N>
+where
+    F: field::Field,
+{
+    assert_eq!(M, K + N, "implicitization needs K+N variables");
+    let gens = params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let param = Polynomial::new(
+                param
+                    .monos
+                    .iter()
+                    .map(|mono| {
+                        let mut degree = [0; M];
+                        degree[..K].copy_from_slice(&mono.degree);
+                        Monomial::new(mono.coef, degree)
+                    })
+                    .collect(),
+            );
+            Polynomial::variable(K + i).sub(&param)
+        })
+        .collect();
+    eliminate_first(gens)
+}
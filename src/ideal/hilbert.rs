@@ -0,0 +1,132 @@
+use super::operation::Ideal;
+use super::projective::projective_closure;
+use crate::algebra::field;
+use crate::algebra::rational::Rational;
+use crate::polynomial::univariate::Univariate;
+
+// HilbertSeries
+// 次数つき環のヒルベルト級数 numerator(t) / (1-t)^vars を表す構造体.
+// numerator[k]はt^kの係数である.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HilbertSeries {
+    pub numerator: Vec<i64>,
+    pub vars: usize,
+}
+
+impl HilbertSeries {
+    // reduced
+    // 分子と分母の共通因子(1-t)を約分し, Q(1) ≠ 0 となる Q(t) / (1-t)^d の (Q, d) を返す.
+    // 級数が0(イデアルが全体)のときは (空, 0) を返す.
+    pub fn reduced(&self) -> (Vec<i64>, usize) {
+        if self.numerator.is_empty() {
+            return (vec![], 0);
+        }
+        let mut numerator = self.numerator.clone();
+        let mut d = self.vars;
+        while d > 0 && numerator.iter().sum::<i64>() == 0 {
+            // Q(t) = (1-t)R(t) のとき R の係数は Q の係数の部分和になる.
+            let mut sum = 0;
+            numerator = numerator[..numerator.len() - 1]
+                .iter()
+                .map(|&c| {
+                    sum += c;
+                    sum
+                })
+                .collect();
+            d -= 1;
+        }
+        (numerator, d)
+    }
+
+    // dimension
+    // 次数つき環のクルル次元, すなわち t = 1 における極の位数を返す.
+    pub fn dimension(&self) -> usize {
+        self.reduced().1
+    }
+
+    // degree
+    // 次数(多様体の次数)Q(1)を返す.
+    pub fn degree(&self) -> i64 {
+        self.reduced().0.iter().sum()
+    }
+
+    // value
+    // ヒルベルト関数の値, すなわちt^sの係数を返す.
+    pub fn value(&self, s: usize) -> i64 {
+        if self.vars == 0 {
+            return self.numerator.get(s).copied().unwrap_or(0);
+        }
+        self.numerator
+            .iter()
+            .enumerate()
+            .take(s + 1)
+            .map(|(k, &c)| c * binomial(s - k + self.vars - 1, self.vars - 1))
+            .sum()
+    }
+
+    // hilbert_polynomial
+    // 十分大きなsでヒルベルト関数と一致する多項式を返す.
+    // Q(t) / (1-t)^d のt^sの係数は Σ_k q_k C(s-k+d-1, d-1) である. d = 0 のときは0多項式になる.
+    pub fn hilbert_polynomial(&self) -> Univariate<Rational> {
+        let (numerator, d) = self.reduced();
+        if d == 0 {
+            return Univariate::zero();
+        }
+        let factorial = (1..d as i64).product::<i64>();
+        let mut ans = Univariate::zero();
+        for (k, &q) in numerator.iter().enumerate() {
+            // C(s-k+d-1, d-1) = (s-k+1)(s-k+2)...(s-k+d-1) / (d-1)!
+            let term = (1..d as i64).fold(
+                Univariate::constant(Rational::new(q, factorial)),
+                |acc, j| {
+                    acc.mul(&Univariate::new(vec![
+                        Rational::new(j - k as i64, 1),
+                        Rational::new(1, 1),
+                    ]))
+                },
+            );
+            ans = ans.add(&term);
+        }
+        ans
+    }
+}
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // hilbert_series
+    // 斉次イデアルIについてR/Iのヒルベルト級数を分母 (1-t)^N で返す.
+    // R/I と R/in(I) のヒルベルト関数は等しいので, 先頭単項式のみから計算する.
+    // 基底が斉次でない場合はNoneを返す. その場合は affine_hilbert_series を用いる.
+    pub fn hilbert_series(&self) -> Option<HilbertSeries> {
+        if !self.basis.iter().all(|f| f.is_homogeneous()) {
+            return None;
+        }
+        Some(self.leading_ideal().hilbert_series())
+    }
+}
+
+// affine_hilbert_series
+// 斉次とは限らないイデアルIについて, 付随する次数つき環 gr(R/I) のヒルベルト級数を分母 (1-t)^N で返す.
+// t^sの係数は dim R_{≤s}/I_{≤s} - dim R_{≤s-1}/I_{≤s-1} で, 次元と次数はV(I)の次元と次数に等しい.
+// 射影閉包 I^h のヒルベルト級数に (1-t) をかけたものなので, 分子は I^h のものと同じになる.
+// 射影閉包の計算のため M = N+1, K = N+2 とする.
+pub fn affine_hilbert_series<F, const N: usize, const M: usize, const K: usize>(
+    ideal: &Ideal<F, N>,
+) -> HilbertSeries
+where
+    F: field::Field,
+{
+    let series = projective_closure::<F, N, M, K>(ideal)
+        .hilbert_series()
+        .unwrap();
+    HilbertSeries {
+        numerator: series.numerator,
+        vars: N,
+    }
+}
+
+fn binomial(n: usize, k: usize) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) as i64 / (i + 1) as i64)
+}
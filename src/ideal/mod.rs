@@ -0,0 +1,15 @@
+pub mod dimension;
+pub mod elimination;
+pub mod hilbert;
+pub mod monomial;
+pub mod operation;
+pub mod primary;
+pub mod projective;
+pub mod quotient;
+pub mod radical;
+pub mod ring;
+pub mod singular;
+pub mod standard;
+
+#[cfg(test)]
+mod test;
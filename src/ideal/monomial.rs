@@ -0,0 +1,250 @@
+use super::hilbert::HilbertSeries;
+use super::operation::Ideal;
+use crate::algebra::field;
+use crate::polynomial::monomial::Monomial;
+
+// MonomialIdeal
+// N変数の単項式イデアルを表す構造体. 単項式は次数のベクトルで表す.
+// gensは極小生成系で, 辞書式順序で大きいものから並ぶ. したがって同じイデアルは同じgensを持つ.
+// 単項式イデアルの演算は次数の最大・最小で書けるので, グレブナー基底の計算を必要としない.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonomialIdeal<const N: usize> {
+    pub gens: Vec<[u32; N]>,
+}
+
+impl<const N: usize> MonomialIdeal<N> {
+    // new
+    // gensで生成される単項式イデアルを作る. 他の生成元で割り切れる生成元は取り除く.
+    pub fn new(gens: Vec<[u32; N]>) -> MonomialIdeal<N> {
+        let mut minimal: Vec<[u32; N]> = Vec::new();
+        for g in gens.iter() {
+            if minimal.iter().any(|m| divides(m, g)) {
+                continue;
+            }
+            minimal.retain(|m| !divides(g, m));
+            minimal.push(*g);
+        }
+        minimal.sort_by(|a, b| b.cmp(a));
+        MonomialIdeal { gens: minimal }
+    }
+
+    // from_monomials
+    // 単項式の次数のみを用いて単項式イデアルを作る. 係数は無視する.
+    pub fn from_monomials<F>(monos: &[Monomial<F, N>]) -> MonomialIdeal<N>
+    where
+        F: field::Field,
+    {
+        MonomialIdeal::new(monos.iter().map(|mono| mono.degree).collect())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.gens.is_empty()
+    }
+
+    pub fn is_unit(&self) -> bool {
+        self.gens.contains(&[0; N])
+    }
+
+    // contains
+    // 単項式x^degreeを含むか, すなわちいずれかの生成元で割り切れるか判定する.
+    pub fn contains(&self, degree: &[u32; N]) -> bool {
+        self.gens.iter().any(|g| divides(g, degree))
+    }
+
+    // is_subset
+    // selfがotherに含まれるか判定する.
+    pub fn is_subset(&self, other: &MonomialIdeal<N>) -> bool {
+        self.gens.iter().all(|g| other.contains(g))
+    }
+
+    // sum
+    // 和 I + J を返す. 生成元を合わせたものが生成系になる.
+    pub fn sum(&self, other: &MonomialIdeal<N>) -> MonomialIdeal<N> {
+        MonomialIdeal::new(self.gens.iter().chain(other.gens.iter()).copied().collect())
+    }
+
+    // product
+    // 積 IJ を返す. 生成元の積が生成系になる.
+    pub fn product(&self, other: &MonomialIdeal<N>) -> MonomialIdeal<N> {
+        MonomialIdeal::new(
+            self.gens
+                .iter()
+                .flat_map(|a| other.gens.iter().map(move |b| zip(a, b, |x, y| x + y)))
+                .collect(),
+        )
+    }
+
+    // intersection
+    // 共通部分 I ∩ J を返す. 生成元の最小公倍数が生成系になる.
+    pub fn intersection(&self, other: &MonomialIdeal<N>) -> MonomialIdeal<N> {
+        MonomialIdeal::new(
+            self.gens
+                .iter()
+                .flat_map(|a| other.gens.iter().map(move |b| zip(a, b, u32::max)))
+                .collect(),
+        )
+    }
+
+    // quotient_by_monomial
+    // イデアル商 I : x^degree を返す. 生成元をそれぞれ最大公約数で割ったものが生成系になる.
+    pub fn quotient_by_monomial(&self, degree: &[u32; N]) -> MonomialIdeal<N> {
+        MonomialIdeal::new(
+            self.gens
+                .iter()
+                .map(|g| zip(g, degree, u32::saturating_sub))
+                .collect(),
+        )
+    }
+
+    // quotient
+    // イデアル商 I : J を返す. Jの生成元mについての I : m の共通部分になる.
+    pub fn quotient(&self, other: &MonomialIdeal<N>) -> MonomialIdeal<N> {
+        other
+            .gens
+            .iter()
+            .fold(MonomialIdeal::new(vec![[0; N]]), |acc, m| {
+                acc.intersection(&self.quotient_by_monomial(m))
+            })
+    }
+
+    // irreducible_decomposition
+    // 既約分解, すなわち変数のべきで生成されるイデアルの無駄のない共通部分として表す.
+    // 生成元 m = x_i^a m' (m' ≠ 1 は x_i を含まない) について I = (I + x_i^a) ∩ (I + m') を用いて分ける.
+    // 最後に他の成分を含む成分を取り除く. 0イデアルの場合は成分は0イデアル1つとする.
+    pub fn irreducible_decomposition(&self) -> Vec<MonomialIdeal<N>> {
+        let mut components: Vec<MonomialIdeal<N>> = Vec::new();
+        for component in self.split() {
+            if components.iter().any(|c| c.is_subset(&component)) {
+                continue;
+            }
+            components.retain(|c| !component.is_subset(c));
+            components.push(component);
+        }
+        components.sort_by(|a, b| b.gens.cmp(&a.gens));
+        components
+    }
+
+    fn split(&self) -> Vec<MonomialIdeal<N>> {
+        let m = match self
+            .gens
+            .iter()
+            .find(|g| g.iter().filter(|&&deg| deg > 0).count() > 1)
+        {
+            Some(m) => m,
+            None => return vec![self.clone()],
+        };
+        let i = m.iter().position(|&deg| deg > 0).unwrap();
+        let mut power = [0; N];
+        power[i] = m[i];
+        let mut rest = *m;
+        rest[i] = 0;
+        let mut ans = self.sum(&MonomialIdeal::new(vec![power])).split();
+        ans.extend(self.sum(&MonomialIdeal::new(vec![rest])).split());
+        ans
+    }
+
+    // alexander_dual
+    // 各生成元を割り切る単項式x^aに関するアレクサンダー双対 I^[a] を返す.
+    // 生成元 x^b に対して (x_i^{a_i + 1 - b_i} : b_i ≥ 1) とおき, それらの共通部分をとる.
+    // 平方自由な単項式イデアルでは a = (1, ..., 1) とすると通常のアレクサンダー双対になる.
+    pub fn alexander_dual(&self, a: &[u32; N]) -> MonomialIdeal<N> {
+        assert!(
+            self.gens.iter().all(|g| divides(g, a)),
+            "every generator must divide x^a"
+        );
+        self.gens
+            .iter()
+            .fold(MonomialIdeal::new(vec![[0; N]]), |acc, b| {
+                let gens = (0..N)
+                    .filter(|&i| b[i] >= 1)
+                    .map(|i| {
+                        let mut degree = [0; N];
+                        degree[i] = a[i] + 1 - b[i];
+                        degree
+                    })
+                    .collect();
+                acc.intersection(&MonomialIdeal::new(gens))
+            })
+    }
+
+    // hilbert_numerator
+    // R/I のヒルベルト級数の分母 (1-t)^N に対する分子を返す. numerator[k]はt^kの係数である.
+    // 生成元の台が互いに素なら Π(1 - t^{deg m}) になる. そうでなければ2つ以上の生成元に現れる変数x_iを選び,
+    // ピボット p = x_i^e (eはx_iの次数の正の最小値) について N(I) = N(I + p) + t^e N(I : p) で再帰的に求める.
+    pub fn hilbert_numerator(&self) -> Vec<i64> {
+        let pivot = (0..N)
+            .map(|i| (self.gens.iter().filter(|g| g[i] > 0).count(), i))
+            .max()
+            .filter(|&(count, _)| count > 1);
+        let mut ans = match pivot {
+            None => self.gens.iter().fold(vec![1], |acc, g| {
+                let d = g.iter().sum::<u32>() as usize;
+                let mut next = acc.clone();
+                next.resize(acc.len() + d, 0);
+                for (k, &c) in acc.iter().enumerate() {
+                    next[k + d] -= c;
+                }
+                next
+            }),
+            Some((_, i)) => {
+                let e = self
+                    .gens
+                    .iter()
+                    .filter(|g| g[i] > 0)
+                    .map(|g| g[i])
+                    .min()
+                    .unwrap();
+                let mut p = [0; N];
+                p[i] = e;
+                let mut ans = self.sum(&MonomialIdeal::new(vec![p])).hilbert_numerator();
+                let quotient = self.quotient_by_monomial(&p).hilbert_numerator();
+                let e = e as usize;
+                if ans.len() < quotient.len() + e {
+                    ans.resize(quotient.len() + e, 0);
+                }
+                for (k, c) in quotient.into_iter().enumerate() {
+                    ans[k + e] += c;
+                }
+                ans
+            }
+        };
+        while ans.last() == Some(&0) {
+            ans.pop();
+        }
+        ans
+    }
+
+    // hilbert_series
+    // R/I のヒルベルト級数を返す.
+    pub fn hilbert_series(&self) -> HilbertSeries {
+        HilbertSeries {
+            numerator: self.hilbert_numerator(),
+            vars: N,
+        }
+    }
+}
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // leading_ideal
+    // 先頭単項式で生成される単項式イデアル in(I) を返す.
+    pub fn leading_ideal(&self) -> MonomialIdeal<N> {
+        MonomialIdeal::new(self.basis.iter().map(|f| f.monos[0].degree).collect())
+    }
+}
+
+// divides
+// x^aがx^bを割り切るか判定する.
+fn divides<const N: usize>(a: &[u32; N], b: &[u32; N]) -> bool {
+    (0..N).all(|i| a[i] <= b[i])
+}
+
+fn zip<const N: usize>(a: &[u32; N], b: &[u32; N], f: impl Fn(u32, u32) -> u32) -> [u32; N] {
+    let mut ans = [0; N];
+    for i in 0..N {
+        ans[i] = f(a[i], b[i]);
+    }
+    ans
+}
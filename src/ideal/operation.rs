@@ -0,0 +1,122 @@
+use crate::algebra::field;
+use crate::buchberger::grobner;
+use crate::polynomial::poly::{self, Polynomial};
+
+// Ideal
+// 係数体をFとしてN変数の多項式環のイデアルを表す構造体.
+// basisは簡約グレブナー基底で, 先頭単項式が単項式順序で大きいものから並ぶ.
+// したがって同じイデアルは同じbasisを持つ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ideal<F, const N: usize>
+where
+    F: field::Field,
+{
+    pub basis: Vec<Polynomial<F, N>>,
+}
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // new
+    // gensで生成されるイデアルを作る.
+    pub fn new(gens: Vec<Polynomial<F, N>>) -> Ideal<F, N> {
+        let mut basis = grobner::reduced_grobner(gens);
+        basis.sort_by(|f, g| g.monos[0].partial_cmp(&f.monos[0]).unwrap());
+        Ideal { basis }
+    }
+
+    // is_zero
+    // 0イデアルか判定する.
+    pub fn is_zero(&self) -> bool {
+        self.basis.is_empty()
+    }
+
+    // is_unit
+    // 多項式環全体か, すなわち1を含むか判定する.
+    pub fn is_unit(&self) -> bool {
+        self.basis.len() == 1 && self.basis[0].monos[0].total_degree() == 0
+    }
+
+    // reduce
+    // polyを簡約グレブナー基底で簡約化した正規形を返す.
+    pub fn reduce(&self, poly: &Polynomial<F, N>) -> Polynomial<F, N> {
+        poly::simplify(poly.clone(), &self.basis).1
+    }
+
+    // contains
+    // polyがイデアルに含まれるか判定する.
+    pub fn contains(&self, poly: &Polynomial<F, N>) -> bool {
+        self.reduce(poly).is_zero()
+    }
+
+    // is_subset
+    // selfがotherに含まれるか判定する.
+    pub fn is_subset(&self, other: &Ideal<F, N>) -> bool {
+        self.basis.iter().all(|poly| other.contains(poly))
+    }
+
+    // sum
+    // イデアルの和 I + J を返す.
+    pub fn sum(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        Ideal::new(
+            self.basis
+                .iter()
+                .chain(other.basis.iter())
+                .cloned()
+                .collect(),
+        )
+    }
+
+    // product
+    // イデアルの積 IJ を返す. 生成元どうしの積で生成される.
+    pub fn product(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        let mut gens = Vec::with_capacity(self.basis.len() * other.basis.len());
+        for f in self.basis.iter() {
+            for g in other.basis.iter() {
+                gens.push(f.mul_poly(g));
+            }
+        }
+        Ideal::new(gens)
+    }
+
+    // intersection
+    // イデアルの共通部分 I ∩ J を返す.
+    // 新しい変数tを先頭に加えたM = N+1変数の多項式環で tI + (1-t)J のグレブナー基底を求め,
+    // tを含まない元を取り出す. tは単項式順序で最大の変数なので, これは消去順序になっている.
+    pub fn intersection<const M: usize>(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        assert_eq!(M, N + 1, "intersection needs one extra variable");
+        let t = Polynomial::<F, M>::variable(0);
+        let one_minus_t = Polynomial::constant(F::unit()).sub(&t);
+        let gens = self
+            .basis
+            .iter()
+            .map(|f| poly::extend::<F, N, M>(f).mul_poly(&t))
+            .chain(
+                other
+                    .basis
+                    .iter()
+                    .map(|g| poly::extend::<F, N, M>(g).mul_poly(&one_minus_t)),
+            )
+            .collect();
+        eliminate_first(gens)
+    }
+}
+
+// eliminate_first
+// M変数の多項式で生成されるイデアルと, 後ろのN変数の多項式環との共通部分を返す.
+// 先頭のM-N個の変数が単項式順序で大きいので, 辞書式順序のグレブナー基底のうち
+// それらを含まない元が共通部分のグレブナー基底になる.
+pub fn eliminate_first<F, const M: usize, const N: usize>(
+    gens: Vec<Polynomial<F, M>>,
+) -> Ideal<F, N>
+where
+    F: field::Field,
+{
+    Ideal::new(
+        grobner::reduced_grobner(gens)
+            .iter()
+            .filter_map(poly::restrict::<F, M, N>)
+            .collect(),
+    )
+}
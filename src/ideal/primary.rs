@@ -0,0 +1,458 @@
+use super::operation::Ideal;
+use super::ring::QuotientRing;
+use crate::algebra::field;
+use crate::factorization::multivariate::Factorize;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::Univariate;
+
+// PrimaryComponent
+// 準素分解の成分. primaryは準素イデアルで, primeはその根基(付随する素イデアル)である.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryComponent<F, const N: usize>
+where
+    F: field::Field,
+{
+    pub primary: Ideal<F, N>,
+    pub prime: Ideal<F, N>,
+}
+
+// 一般の位置にする座標変換を試す回数の上限.
+const MAX_ATTEMPTS: usize = 25;
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: Factorize,
+{
+    // primary_decomposition
+    // I = Q_1 ∩ ... ∩ Q_r となる準素イデアルQ_iと付随素イデアルを返す. 付随素イデアルは相異なり, どのQ_iも省けない.
+    // 単項式イデアルは既約分解から, F_p上の0次元イデアルはフロベニウス写像を用いて分解する.
+    // それ以外は Gianni–Trager–Zacharias の方法による. 次元に等しい要素数の独立集合Uの変数を係数体に入れると
+    // I は k(U) 上0次元になり, その分解を縮約したものが I : h^∞ の分解になる(decompose_equidimensional).
+    // 残りは I = (I : h^∞) ∩ (I + (h^s)) の I + (h^s) を再帰的に分解する.
+    // 多変数多項式の既約分解を用いるので係数体はF_pかQとする. 全体のイデアルに対しては空のVecを返す.
+    // 正標数で非分離な拡大が現れる場合など, 一般の位置にする座標変換が見つからない場合はエラーを返す.
+    // 変数を加える計算のため M = N+1 とする.
+    pub fn primary_decomposition<const M: usize>(
+        &self,
+    ) -> Result<Vec<PrimaryComponent<F, N>>, &'static str> {
+        if self.is_unit() {
+            return Ok(vec![]);
+        }
+        if self.basis.iter().all(|f| f.monos.len() == 1) {
+            return Ok(self.monomial_decomposition());
+        }
+        let mut components = Vec::new();
+        self.decompose::<M>(&mut components)?;
+        Ok(irredundant::<F, N, M>(components))
+    }
+
+    // decompose
+    // 準素成分を重複や無駄を許してansに加える.
+    fn decompose<const M: usize>(
+        &self,
+        ans: &mut Vec<PrimaryComponent<F, N>>,
+    ) -> Result<(), &'static str> {
+        if self.is_unit() {
+            return Ok(());
+        }
+        if self.basis.iter().all(|f| f.monos.len() == 1) {
+            ans.extend(self.monomial_decomposition());
+            return Ok(());
+        }
+        if F::characteristic() > 0 && self.is_zero_dimensional() {
+            return self.split_zero_dimensional::<M>(ans);
+        }
+        let u = self.independent_set().unwrap();
+        let (equidimensional, h, _) = self.contraction::<M>(&u);
+        ans.extend(equidimensional.decompose_equidimensional::<M>(&u)?);
+        if h.total_degree() == 0 {
+            return Ok(());
+        }
+        // I : h^s = I : h^∞ となるsを探す.
+        let mut power = h.clone();
+        while self.quotient_by_poly::<M>(&power) != equidimensional {
+            power = power.mul_poly(&h);
+        }
+        let mut gens = self.basis.clone();
+        gens.push(power);
+        Ideal::new(gens).decompose::<M>(ans)
+    }
+
+    // contraction
+    // uの変数をパラメータとみなした k(U)[Y] への拡大を k[X] に縮約したイデアル I k(U)[Y] ∩ k[X] を返す.
+    // Yを先に並べた辞書式順序のグレブナー基底は k(U)[Y] でもグレブナー基底なので,
+    // そのYについての先頭係数(Uの変数の多項式)の積をhとすると縮約は I : h^∞ になる.
+    // 縮約, h, 各基底の先頭単項式のYの部分を返す.
+    fn contraction<const M: usize>(
+        &self,
+        u: &[usize],
+    ) -> (Ideal<F, N>, Polynomial<F, N>, Vec<[u32; N]>) {
+        let order: Vec<usize> = (0..N)
+            .filter(|i| !u.contains(i))
+            .chain(u.iter().copied())
+            .collect();
+        let mut perm = [0; N];
+        let mut inv = [0; N];
+        for (pos, &i) in order.iter().enumerate() {
+            perm[i] = pos;
+            inv[pos] = i;
+        }
+        let y = N - u.len();
+        let basis = Ideal::new(self.basis.iter().map(|f| f.permute(&perm)).collect()).basis;
+        let mut h = Polynomial::constant(F::unit());
+        let mut leading = Vec::with_capacity(basis.len());
+        for g in basis.iter() {
+            let lead = g.monos[0].degree;
+            let mut lc = Polynomial::new(
+                g.monos
+                    .iter()
+                    .filter(|mono| mono.degree[..y] == lead[..y])
+                    .map(|mono| {
+                        let mut degree = mono.degree;
+                        degree[..y].fill(0);
+                        Monomial::new(mono.coef, degree)
+                    })
+                    .collect(),
+            )
+            .permute(&inv);
+            lc.norm();
+            if h.div_exact(&lc).is_none() {
+                h = h.mul_poly(&lc);
+            }
+            let mut part = [0; N];
+            for pos in 0..y {
+                part[inv[pos]] = lead[pos];
+            }
+            leading.push(part);
+        }
+        let ideal = if h.total_degree() == 0 {
+            self.clone()
+        } else {
+            self.saturation_by_poly::<M>(&h)
+        };
+        (ideal, h, leading)
+    }
+
+    // decompose_equidimensional
+    // uの変数をパラメータとしたとき k(U) 上0次元で, I = I k(U)[Y] ∩ k[X] となるイデアルを分解する.
+    // Yの最後の変数x_tに他のYの変数の定数倍を足して座標を変え, I ∩ k[U, x_t] の生成元 g = Π g_i^{e_i} を分解する.
+    // k(U)[x_t] で g_i^{e_i} は互いに素なので I k(U)[Y] = ∩ (I + (g_i^{e_i})) となり, これらを縮約したものをQ_iとする.
+    // Q_iに g_i と separable_parts を加えた k(U) 上の根基の先頭単項式がx_t以外のYの変数をすべて含めば,
+    // 剰余環は k(U)[x_t]/(g_i) と同型な体なので根基は素イデアルで, Q_iは準素である(一般の位置).
+    // そうでなければ別の座標変換で試し直す.
+    fn decompose_equidimensional<const M: usize>(
+        &self,
+        u: &[usize],
+    ) -> Result<Vec<PrimaryComponent<F, N>>, &'static str> {
+        let y: Vec<usize> = (0..N).filter(|i| !u.contains(i)).collect();
+        let (&t, others) = y.split_last().unwrap();
+        'attempt: for attempt in 0..MAX_ATTEMPTS {
+            let shift: Vec<(usize, F)> = others
+                .iter()
+                .enumerate()
+                .map(|(k, &j)| {
+                    // attemptを5進法で表した各桁を 0, 1, -1, 2, -2 に対応させる.
+                    let digit = attempt / 5usize.pow(k as u32) % 5;
+                    let c = F::from_usize(digit.div_ceil(2));
+                    (j, if digit.is_multiple_of(2) { -c } else { c })
+                })
+                .collect();
+            let ideal = self.change_coordinates(t, &shift, F::unit());
+            let eliminant = ideal.eliminate(others);
+            if eliminant.basis.len() != 1 {
+                continue;
+            }
+            let (_, factors) = F::factorize::<N, M>(&eliminant.basis[0])?;
+            let mut components = Vec::new();
+            for (g, e) in factors.iter() {
+                if g.monos.iter().all(|mono| mono.degree[t] == 0) {
+                    continue;
+                }
+                let mut gens = ideal.basis.clone();
+                gens.push((1..*e).fold(g.clone(), |acc, _| acc.mul_poly(g)));
+                let (primary, _, _) = Ideal::new(gens).contraction::<M>(u);
+                // x_tについては, 根基と k(U)[x_t] の共通部分は (g_i) である.
+                let mut gens = primary.basis.clone();
+                gens.push(g.clone());
+                match primary.separable_parts::<M>(&y, others)? {
+                    Some(parts) => gens.extend(parts),
+                    None => continue 'attempt,
+                }
+                let (prime, _, leading) = Ideal::new(gens).contraction::<M>(u);
+                let general = !leading.contains(&[0; N])
+                    && others.iter().all(|&j| {
+                        let mut degree = [0; N];
+                        degree[j] = 1;
+                        leading.contains(&degree)
+                    });
+                if !general {
+                    continue 'attempt;
+                }
+                components.push(PrimaryComponent {
+                    primary: primary.change_coordinates(t, &shift, -F::unit()),
+                    prime: prime.change_coordinates(t, &shift, -F::unit()),
+                });
+            }
+            return Ok(components);
+        }
+        Err("no coordinates in general position were found")
+    }
+
+    // separable_parts
+    // uの変数をパラメータとしたとき k(U)[Y] で0次元で縮約されたイデアルについて, 各変数 y ∈ targets ごとに
+    // I ∩ k[U, y] の生成元の, yを含む相異なる既約因子の積を返す. ysはYの変数である.
+    // これらをIに加えたものは k(U) 上の根基になる(Seidenbergの補題).
+    // 生成元が1つに定まらない場合はNoneを, yについての微分が0になる(非分離な)因子がある場合はエラーを返す.
+    fn separable_parts<const M: usize>(
+        &self,
+        ys: &[usize],
+        targets: &[usize],
+    ) -> Result<Option<Vec<Polynomial<F, N>>>, &'static str> {
+        let mut parts = Vec::with_capacity(targets.len());
+        for &y in targets.iter() {
+            let others: Vec<usize> = ys.iter().copied().filter(|&j| j != y).collect();
+            let eliminant = self.eliminate(&others);
+            if eliminant.basis.len() != 1 {
+                return Ok(None);
+            }
+            let (_, factors) = F::factorize::<N, M>(&eliminant.basis[0])?;
+            let mut part = Polynomial::constant(F::unit());
+            for (g, _) in factors.iter() {
+                if g.monos.iter().all(|mono| mono.degree[y] == 0) {
+                    continue;
+                }
+                if g.derivative(y).is_zero() {
+                    return Err(
+                        "primary decomposition with an inseparable factor is not supported",
+                    );
+                }
+                part = part.mul_poly(g);
+            }
+            parts.push(part);
+        }
+        Ok(Some(parts))
+    }
+
+    // change_coordinates
+    // x_t を x_t + sign Σ c_j x_j ((j, c_j) ∈ shift) に置き換えたイデアルを返す.
+    fn change_coordinates(&self, t: usize, shift: &[(usize, F)], sign: F) -> Ideal<F, N> {
+        let polys: [Polynomial<F, N>; N] = std::array::from_fn(|i| {
+            let mut x = Polynomial::variable(i);
+            if i == t {
+                for &(j, c) in shift.iter().filter(|(_, c)| *c != F::zero()) {
+                    x = x.add(&Polynomial::variable(j).mul(&Monomial::new(sign * c, [0; N])));
+                }
+            }
+            x
+        });
+        Ideal::new(self.basis.iter().map(|f| f.substitute(&polys)).collect())
+    }
+
+    // monomial_decomposition
+    // 単項式イデアルの既約分解の成分は変数のべきで生成されるので準素イデアルで,
+    // 根基は現れる変数で生成される. 根基が同じ成分の共通部分も準素なので, まとめて無駄のない分解にする.
+    fn monomial_decomposition(&self) -> Vec<PrimaryComponent<F, N>> {
+        let to_ideal = |gens: &[[u32; N]]| {
+            Ideal::new(
+                gens.iter()
+                    .map(|degree| Polynomial::new(vec![Monomial::new(F::unit(), *degree)]))
+                    .collect(),
+            )
+        };
+        let mut groups: Vec<(Vec<usize>, Ideal<F, N>)> = Vec::new();
+        for component in self.leading_ideal().irreducible_decomposition() {
+            let vars: Vec<usize> = (0..N)
+                .filter(|&i| component.gens.iter().any(|g| g[i] > 0))
+                .collect();
+            let primary = to_ideal(&component.gens);
+            match groups.iter_mut().find(|(v, _)| *v == vars) {
+                Some((_, ideal)) => *ideal = ideal.intersection_monomial(&primary),
+                None => groups.push((vars, primary)),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(vars, primary)| {
+                let prime = Ideal::new(vars.iter().map(|&i| Polynomial::variable(i)).collect());
+                PrimaryComponent { primary, prime }
+            })
+            .collect()
+    }
+
+    // intersection_monomial
+    // 単項式イデアル同士の共通部分. 生成元の最小公倍数で生成されるので, 変数を加えずに計算できる.
+    fn intersection_monomial(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        let a = self.leading_ideal();
+        let b = other.leading_ideal();
+        Ideal::new(
+            a.intersection(&b)
+                .gens
+                .iter()
+                .map(|degree| Polynomial::new(vec![Monomial::new(F::unit(), *degree)]))
+                .collect(),
+        )
+    }
+
+    // split_zero_dimensional
+    // F_p上の0次元イデアルJを準素成分に分ける. A = R/√J は有限個の有限体の直積で,
+    // フロベニウス写像 a ↦ a^p の固定点全体はF_pを成分の数だけ並べたものになる.
+    // 固定点の空間が1次元ならAは体なので√Jは極大でJは準素である.
+    // そうでなければ定数でない固定点fをとる. fは各成分でF_pの値をとり, その値全体はAでのfの最小多項式の根である.
+    // 根cごとに J + ((f - c)^m) (mは dim R/J 以上) が値cをとる成分の共通部分になるので, 再帰的に分ける.
+    fn split_zero_dimensional<const M: usize>(
+        &self,
+        ans: &mut Vec<PrimaryComponent<F, N>>,
+    ) -> Result<(), &'static str> {
+        let prime = self.radical::<M>().unwrap();
+        let monos = prime.standard_monomials().unwrap();
+        let ring = QuotientRing::from_ideal(&prime);
+        let p = F::characteristic();
+        let n = monos.len();
+        let coordinates = |poly: &Polynomial<F, N>| {
+            let mut v = vec![F::zero(); n];
+            for term in poly.monos.iter() {
+                let k = monos.iter().position(|m| m.degree == term.degree).unwrap();
+                v[k] = term.coef;
+            }
+            v
+        };
+        // フロベニウス写像から恒等写像を引いたものの表現行列
+        let mut matrix = vec![vec![F::zero(); n]; n];
+        for (j, mono) in monos.iter().enumerate() {
+            let b = ring.element(&Polynomial::new(vec![mono.clone()]));
+            for (k, c) in coordinates(b.pow(p as u64).poly()).into_iter().enumerate() {
+                matrix[k][j] = c;
+            }
+            matrix[j][j] = matrix[j][j] - F::unit();
+        }
+        let fixed = kernel(matrix);
+        if fixed.len() <= 1 {
+            ans.push(PrimaryComponent {
+                primary: self.clone(),
+                prime,
+            });
+            return Ok(());
+        }
+        // 定数項以外に成分を持つ固定点を選ぶ.
+        let v = fixed
+            .iter()
+            .find(|v| {
+                monos
+                    .iter()
+                    .zip(v.iter())
+                    .any(|(mono, &c)| mono.total_degree() > 0 && c != F::zero())
+            })
+            .unwrap();
+        let f = Polynomial::new(
+            monos
+                .iter()
+                .zip(v.iter())
+                .filter(|(_, &c)| c != F::zero())
+                .map(|(mono, &c)| Monomial::new(c, mono.degree))
+                .collect(),
+        );
+        // fのべきが1次従属になるまで並べ, 最小多項式を求める.
+        let mut powers: Vec<Vec<F>> = Vec::new();
+        let mut power = ring.unit();
+        let minimal = loop {
+            powers.push(coordinates(power.poly()));
+            let matrix = (0..n)
+                .map(|k| powers.iter().map(|v| v[k]).collect())
+                .collect();
+            if let Some(coefs) = kernel(matrix).pop() {
+                break coefs;
+            }
+            power = power.mul(&ring.element(&f));
+        };
+        // 最小多項式は t^p - t を割り切るので, 相異なる1次式の積に分解される.
+        let roots = F::roots(&Univariate::new(minimal))?;
+        let m = self.quotient_dimension().unwrap() as u64;
+        let ring = QuotientRing::from_ideal(self);
+        for root in roots {
+            let g = f.sub(&Polynomial::constant(root));
+            let mut gens = self.basis.clone();
+            gens.push(ring.element(&g).pow(m).poly().clone());
+            Ideal::new(gens).split_zero_dimensional::<M>(ans)?;
+        }
+        Ok(())
+    }
+}
+
+// irredundant
+// 付随素イデアルが同じ成分は共通部分をとってまとめ, 他の成分の共通部分に含まれる成分を取り除く.
+fn irredundant<F, const N: usize, const M: usize>(
+    components: Vec<PrimaryComponent<F, N>>,
+) -> Vec<PrimaryComponent<F, N>>
+where
+    F: field::Field,
+{
+    let mut merged: Vec<PrimaryComponent<F, N>> = Vec::new();
+    for component in components {
+        match merged.iter_mut().find(|c| c.prime == component.prime) {
+            Some(c) => c.primary = c.primary.intersection::<M>(&component.primary),
+            None => merged.push(component),
+        }
+    }
+    let mut i = 0;
+    while i < merged.len() {
+        let others = merged
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, c)| c.primary.clone())
+            .reduce(|acc, q| acc.intersection::<M>(&q));
+        match others {
+            Some(others) if others.is_subset(&merged[i].primary) => {
+                merged.remove(i);
+            }
+            _ => i += 1,
+        }
+    }
+    merged
+}
+
+// kernel
+// 行列の核の基底を返す. 掃き出し法で簡約階段形にし, 主成分でない列ごとに1つずつ基底を作る.
+fn kernel<F>(mut matrix: Vec<Vec<F>>) -> Vec<Vec<F>>
+where
+    F: field::Field,
+{
+    let rows = matrix.len();
+    let cols = matrix.first().map_or(0, |row| row.len());
+    let mut pivots = Vec::new();
+    let mut r = 0;
+    for c in 0..cols {
+        let k = match (r..rows).find(|&k| matrix[k][c] != F::zero()) {
+            Some(k) => k,
+            None => continue,
+        };
+        matrix.swap(r, k);
+        let inv = F::unit() / matrix[r][c];
+        for x in matrix[r].iter_mut() {
+            *x = *x * inv;
+        }
+        let pivot = matrix[r].clone();
+        for (k, row) in matrix.iter_mut().enumerate() {
+            if k != r && row[c] != F::zero() {
+                let factor = row[c];
+                for (x, &y) in row.iter_mut().zip(pivot.iter()) {
+                    *x = *x - factor * y;
+                }
+            }
+        }
+        pivots.push(c);
+        r += 1;
+    }
+    (0..cols)
+        .filter(|c| !pivots.contains(c))
+        .map(|free| {
+            let mut v = vec![F::zero(); cols];
+            v[free] = F::unit();
+            for (row, &c) in pivots.iter().enumerate() {
+                v[c] = -matrix[row][free];
+            }
+            v
+        })
+        .collect()
+}
@@ -0,0 +1,26 @@
+use super::operation::Ideal;
+use crate::algebra::field;
+use crate::polynomial::poly::{self, Polynomial};
+
+// projective_closure
+// アフィン多様体V(I)の射影閉包を定める斉次イデアルを, 先頭に変数x_0を加えた M = N+1 変数で返す.
+// 生成元の斉次化だけでは足りないので, 基底の斉次化で生成されるイデアルを x_0 で飽和させる.
+// 飽和の計算にさらに変数を1つ加えるため K = M+1 とする.
+pub fn projective_closure<F, const N: usize, const M: usize, const K: usize>(
+    ideal: &Ideal<F, N>,
+) -> Ideal<F, M>
+where
+    F: field::Field,
+{
+    Ideal::new(ideal.basis.iter().map(poly::homogenize).collect())
+        .saturation_by_poly::<K>(&Polynomial::variable(0))
+}
+
+// affine_part
+// M = N+1 変数のイデアルの生成元の先頭の変数x_0に1を代入し, アフィン部分 x_0 ≠ 0 を定めるイデアルを返す.
+pub fn affine_part<F, const M: usize, const N: usize>(ideal: &Ideal<F, M>) -> Ideal<F, N>
+where
+    F: field::Field,
+{
+    Ideal::new(ideal.basis.iter().map(poly::dehomogenize).collect())
+}
@@ -0,0 +1,61 @@
+use super::operation::{eliminate_first, Ideal};
+use crate::algebra::field;
+use crate::polynomial::poly::{self, Polynomial};
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // quotient_by_poly
+    // イデアル商 I : f を返す. I ∩ (f) の生成元をそれぞれfで割ったもので生成される.
+    // 共通部分の計算に変数を1つ加えるため M = N+1 とする.
+    pub fn quotient_by_poly<const M: usize>(&self, f: &Polynomial<F, N>) -> Ideal<F, N> {
+        if f.is_zero() {
+            return Ideal::new(vec![Polynomial::constant(F::unit())]);
+        }
+        let gens = self
+            .intersection::<M>(&Ideal::new(vec![f.clone()]))
+            .basis
+            .iter()
+            .map(|g| g.div_rem(f).0)
+            .collect();
+        Ideal::new(gens)
+    }
+
+    // quotient
+    // イデアル商 I : J を返す. Jの生成元gについての I : g の共通部分になる.
+    pub fn quotient<const M: usize>(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        let mut ans = Ideal::new(vec![Polynomial::constant(F::unit())]);
+        for g in other.basis.iter() {
+            ans = ans.intersection::<M>(&self.quotient_by_poly::<M>(g));
+        }
+        ans
+    }
+
+    // saturation_by_poly
+    // 飽和 I : f^∞ を返す. イデアル商を繰り返す代わりに, 新しい変数tを加えた
+    // I + (1 - tf) からtを消去することで1回のグレブナー基底の計算で求める. M = N+1 とする.
+    // 変数による飽和もこれで求める. 基底を変数のべきで割る方法は辞書式順序では正しくない.
+    // 例えば (xz - y, y^2) はzを最小とする辞書式順序のグレブナー基底でzで割り切れる元を持たないが,
+    // xy は (xz - y, y^2) : z^∞ に含まれる.
+    pub fn saturation_by_poly<const M: usize>(&self, f: &Polynomial<F, N>) -> Ideal<F, N> {
+        assert_eq!(M, N + 1, "saturation needs one extra variable");
+        if f.is_zero() {
+            return Ideal::new(vec![Polynomial::constant(F::unit())]);
+        }
+        let t = Polynomial::<F, M>::variable(0);
+        let mut gens: Vec<Polynomial<F, M>> = self.basis.iter().map(poly::extend).collect();
+        gens.push(Polynomial::constant(F::unit()).sub(&t.mul_poly(&poly::extend(f))));
+        eliminate_first(gens)
+    }
+
+    // saturation
+    // 飽和 I : J^∞ を返す. Jの生成元gについての I : g^∞ の共通部分になる.
+    pub fn saturation<const M: usize>(&self, other: &Ideal<F, N>) -> Ideal<F, N> {
+        let mut ans = Ideal::new(vec![Polynomial::constant(F::unit())]);
+        for g in other.basis.iter() {
+            ans = ans.intersection::<M>(&self.saturation_by_poly::<M>(g));
+        }
+        ans
+    }
+}
@@ -0,0 +1,58 @@
+use super::operation::Ideal;
+use crate::algebra::field;
+use crate::buchberger::grobner;
+use crate::factorization::squarefree::squarefree_part;
+use crate::polynomial::gcd::Gcd;
+use crate::polynomial::poly::{self, Polynomial};
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // radical_contains
+    // fが根基√Iに含まれるか, すなわちfがV(I)上(代数閉包上の点も含む)で0になるか判定する.
+    // 新しい変数tを加えた I + (1 - tf) のグレブナー基底が{1}になることと同値である(Rabinowitschの方法).
+    // M = N+1 とする.
+    pub fn radical_contains<const M: usize>(&self, f: &Polynomial<F, N>) -> bool {
+        assert_eq!(M, N + 1, "radical membership needs one extra variable");
+        let t = Polynomial::<F, M>::variable(0);
+        let mut gens: Vec<Polynomial<F, M>> = self.basis.iter().map(poly::extend).collect();
+        gens.push(Polynomial::constant(F::unit()).sub(&t.mul_poly(&poly::extend(f))));
+        let basis = grobner::reduced_grobner(gens);
+        basis.len() == 1 && basis[0].monos[0].total_degree() == 0
+    }
+
+    // radical
+    // 0次元イデアルの根基√Iを返す. 0次元でない場合はエラーを返す.
+    // 各変数x_iについて I ∩ k[x_i] の生成元g_iを求め, その無平方部分をIに加える(Seidenbergの補題).
+    // 係数体が完全体(F_pとQ)であることを用いる. 無平方部分の計算のため M = N+1 とする.
+    // 無平方部分に使う最大公約数が求められない場合もエラーを返す.
+    pub fn radical<const M: usize>(&self) -> Result<Ideal<F, N>, &'static str>
+    where
+        F: Gcd,
+    {
+        if self.is_unit() {
+            return Ok(self.clone());
+        }
+        if !self.is_zero_dimensional() {
+            return Err("ideal is not zero-dimensional");
+        }
+        let mut gens = self.basis.clone();
+        for i in 0..N {
+            let others: Vec<usize> = (0..N).filter(|&j| j != i).collect();
+            // 0次元なので消去イデアルは単項イデアルである.
+            let eliminant = self.eliminate(&others);
+            gens.push(squarefree_part::<F, N, M>(&eliminant.basis[0])?);
+        }
+        Ok(Ideal::new(gens))
+    }
+
+    // is_radical
+    // 0次元イデアルが根基イデアルか判定する. 0次元でない場合はエラーを返す.
+    pub fn is_radical<const M: usize>(&self) -> Result<bool, &'static str>
+    where
+        F: Gcd,
+    {
+        Ok(self.radical::<M>()? == *self)
+    }
+}
@@ -0,0 +1,187 @@
+use super::operation::Ideal;
+use crate::algebra::field;
+use crate::buchberger::grobner;
+use crate::polynomial::poly::{self, Polynomial};
+use std::fmt;
+
+// QuotientRing
+// 剰余環 F[x_1, ..., x_N]/I を表す構造体. basisはIの簡約グレブナー基底である.
+// 元は環への参照を持つため, Fieldトレイト(Copyと環によらない unit, zero を要求する)は実装できない.
+// Iが極大イデアルのときは, 0でない元はすべて inverse で逆元を持つ.
+#[derive(Debug, Clone)]
+pub struct QuotientRing<F, const N: usize>
+where
+    F: field::Field,
+{
+    basis: Vec<Polynomial<F, N>>,
+}
+
+// Residue
+// 剰余環の元. polyは常に基底による正規形である.
+// 演算は同じ環(同じQuotientRingへの参照)の元どうしでのみ行え, 異なる環の元を渡すとパニックする.
+#[derive(Debug, Clone)]
+pub struct Residue<'a, F, const N: usize>
+where
+    F: field::Field,
+{
+    ring: &'a QuotientRing<F, N>,
+    poly: Polynomial<F, N>,
+}
+
+impl<F, const N: usize> QuotientRing<F, N>
+where
+    F: field::Field,
+{
+    // new
+    // gensで生成されるイデアルによる剰余環を作る.
+    pub fn new(gens: Vec<Polynomial<F, N>>) -> QuotientRing<F, N> {
+        QuotientRing {
+            basis: grobner::reduced_grobner(gens),
+        }
+    }
+
+    // from_ideal
+    // イデアルによる剰余環を作る. イデアルは簡約グレブナー基底を持つので計算し直さない.
+    pub fn from_ideal(ideal: &Ideal<F, N>) -> QuotientRing<F, N> {
+        QuotientRing {
+            basis: ideal.basis.clone(),
+        }
+    }
+
+    pub fn basis(&self) -> &[Polynomial<F, N>] {
+        &self.basis
+    }
+
+    // element
+    // 多項式の剰余類を返す.
+    pub fn element(&self, poly: &Polynomial<F, N>) -> Residue<'_, F, N> {
+        Residue {
+            ring: self,
+            poly: poly::simplify(poly.clone(), &self.basis).1,
+        }
+    }
+
+    pub fn zero(&self) -> Residue<'_, F, N> {
+        self.element(&Polynomial::new(vec![]))
+    }
+
+    pub fn unit(&self) -> Residue<'_, F, N> {
+        self.element(&Polynomial::constant(F::unit()))
+    }
+}
+
+impl<'a, F, const N: usize> Residue<'a, F, N>
+where
+    F: field::Field,
+{
+    // poly
+    // 正規形の代表元を返す.
+    pub fn poly(&self) -> &Polynomial<F, N> {
+        &self.poly
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    pub fn neg(&self) -> Residue<'a, F, N> {
+        Residue {
+            ring: self.ring,
+            poly: self.poly.neg(),
+        }
+    }
+
+    pub fn add(&self, rhs: &Residue<'a, F, N>) -> Residue<'a, F, N> {
+        assert!(
+            std::ptr::eq(self.ring, rhs.ring),
+            "residues of different rings"
+        );
+        Residue {
+            ring: self.ring,
+            poly: self.poly.add(&rhs.poly),
+        }
+    }
+
+    pub fn sub(&self, rhs: &Residue<'a, F, N>) -> Residue<'a, F, N> {
+        assert!(
+            std::ptr::eq(self.ring, rhs.ring),
+            "residues of different rings"
+        );
+        Residue {
+            ring: self.ring,
+            poly: self.poly.sub(&rhs.poly),
+        }
+    }
+
+    pub fn mul(&self, rhs: &Residue<'a, F, N>) -> Residue<'a, F, N> {
+        assert!(
+            std::ptr::eq(self.ring, rhs.ring),
+            "residues of different rings"
+        );
+        self.ring.element(&self.poly.mul_poly(&rhs.poly))
+    }
+
+    // pow
+    // 繰り返し2乗法でべき乗を求める. 途中の積も正規形に保つ.
+    pub fn pow(&self, mut exp: u64) -> Residue<'a, F, N> {
+        let mut ans = self.ring.unit();
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans = ans.mul(&base);
+            }
+            base = base.mul(&base);
+            exp >>= 1;
+        }
+        ans
+    }
+
+    // inverse
+    // 逆元を返す. 逆元を持たない場合はNoneを返す.
+    // 新しい変数tを加えた I + (ta - 1) の簡約グレブナー基底を辞書式順序で求める.
+    // aが可逆で逆元がbならば t - b がこのイデアルに含まれ, 基底に先頭単項式がtの元として現れる.
+    // 可逆でない場合もそのような元が現れることがあるので, 最後に積が1になるか確かめる. M = N+1 とする.
+    pub fn inverse<const M: usize>(&self) -> Option<Residue<'a, F, N>> {
+        assert_eq!(M, N + 1, "inverse needs one extra variable");
+        let t = Polynomial::<F, M>::variable(0);
+        let mut gens: Vec<Polynomial<F, M>> = self.ring.basis.iter().map(poly::extend).collect();
+        gens.push(
+            t.mul_poly(&poly::extend(&self.poly))
+                .sub(&Polynomial::constant(F::unit())),
+        );
+        let mut t_degree = [0; M];
+        t_degree[0] = 1;
+        let g = grobner::reduced_grobner(gens)
+            .into_iter()
+            .find(|g| g.monos[0].degree == t_degree)?;
+        let inv = self.ring.element(&poly::restrict(&t.sub(&g))?);
+        if self.mul(&inv) == self.ring.unit() {
+            Some(inv)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F, const N: usize> PartialEq for Residue<'_, F, N>
+where
+    F: field::Field,
+{
+    // 異なる環の元は等しくないとする.
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.ring, other.ring) && self.poly == other.poly
+    }
+}
+
+impl<F, const N: usize> fmt::Display for Residue<'_, F, N>
+where
+    F: field::Field + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", self.poly)
+        }
+    }
+}
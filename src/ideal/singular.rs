@@ -0,0 +1,105 @@
+use super::operation::Ideal;
+use crate::algebra::combination::subsets;
+use crate::algebra::field;
+use crate::polynomial::poly::{self, Polynomial};
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // singular_locus
+    // V(I)の特異点全体を定めるイデアル I + (ヤコビ行列のc次小行列式) を返す. c = N - dim I は余次元である.
+    // V(I)が等次元な多様体で, Iが根基イデアルであることを仮定する(ヤコビの判定法).
+    // V(I)が空, すなわちIが全体のときはIをそのまま返す.
+    pub fn singular_locus(&self) -> Ideal<F, N> {
+        let codim = match self.dimension() {
+            Some(dim) => N - dim,
+            None => return self.clone(),
+        };
+        let jac = poly::jacobian(&self.basis);
+        let mut gens = self.basis.clone();
+        gens.extend(minors(&jac, codim));
+        Ideal::new(gens)
+    }
+}
+
+// is_smooth_hypersurface
+// 超曲面 f = 0 が特異点を持たない, すなわち f と偏微分すべてに共通の零点が代数閉包上にもないか判定する.
+pub fn is_smooth_hypersurface<F, const N: usize>(f: &Polynomial<F, N>) -> bool
+where
+    F: field::Field,
+{
+    is_smooth_complete_intersection(std::slice::from_ref(f))
+}
+
+// is_smooth_complete_intersection
+// polysで定まる完全交叉(余次元がpolysの個数に等しい)が特異点を持たないか判定する.
+// polysとヤコビ行列の最大次小行列式で生成されるイデアルが1を含めばよい.
+pub fn is_smooth_complete_intersection<F, const N: usize>(polys: &[Polynomial<F, N>]) -> bool
+where
+    F: field::Field,
+{
+    let mut gens = polys.to_vec();
+    gens.extend(minors(&poly::jacobian(polys), polys.len()));
+    Ideal::new(gens).is_unit()
+}
+
+// minors
+// 行列のsize次小行列式をすべて返す.
+pub fn minors<F, const N: usize>(
+    matrix: &[[Polynomial<F, N>; N]],
+    size: usize,
+) -> Vec<Polynomial<F, N>>
+where
+    F: field::Field,
+{
+    let mut ans = Vec::new();
+    for rows in subsets(matrix.len(), size) {
+        for cols in subsets(N, size) {
+            let det = determinant(
+                &rows
+                    .iter()
+                    .map(|&i| cols.iter().map(|&j| matrix[i][j].clone()).collect())
+                    .collect::<Vec<Vec<Polynomial<F, N>>>>(),
+            );
+            if !det.is_zero() {
+                ans.push(det);
+            }
+        }
+    }
+    ans
+}
+
+// determinant
+// 多項式を成分とする正方行列の行列式を1行目についての余因子展開で求める.
+fn determinant<F, const N: usize>(matrix: &[Vec<Polynomial<F, N>>]) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    if matrix.is_empty() {
+        return Polynomial::constant(F::unit());
+    }
+    let mut ans = Polynomial::new(vec![]);
+    for j in 0..matrix.len() {
+        if matrix[0][j].is_zero() {
+            continue;
+        }
+        let minor: Vec<Vec<Polynomial<F, N>>> = matrix[1..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != j)
+                    .map(|(_, f)| f.clone())
+                    .collect()
+            })
+            .collect();
+        let term = matrix[0][j].mul_poly(&determinant(&minor));
+        ans = if j % 2 == 0 {
+            ans.add(&term)
+        } else {
+            ans.sub(&term)
+        };
+    }
+    ans
+}
@@ -0,0 +1,76 @@
+use super::operation::Ideal;
+use crate::algebra::field;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+
+impl<F, const N: usize> Ideal<F, N>
+where
+    F: field::Field,
+{
+    // standard_monomials
+    // 基底のどの先頭単項式でも割り切れない単項式(標準単項式)を, 単項式順序で大きいものから並べて返す.
+    // 標準単項式はR/Iの線形空間としての基底になる. 有限個になるのは0次元のときのみなので,
+    // 0次元でない場合はNoneを返す. 係数はすべて1とする.
+    pub fn standard_monomials(&self) -> Option<Vec<Monomial<F, N>>> {
+        if self.is_unit() {
+            return Some(vec![]);
+        }
+        if !self.is_zero_dimensional() {
+            return None;
+        }
+        // 各変数について, そのべきだけの先頭単項式の次数が標準単項式の次数の上限になる.
+        let mut bound = [0; N];
+        for f in self.basis.iter() {
+            let degree = f.monos[0].degree;
+            for i in 0..N {
+                if degree[i] > 0 && (0..N).all(|j| j == i || degree[j] == 0) {
+                    bound[i] = degree[i];
+                }
+            }
+        }
+        let mut monos = Vec::new();
+        let mut degree = [0; N];
+        'outer: loop {
+            let mono = Monomial::new(F::unit(), degree);
+            if self.basis.iter().all(|f| !mono.can_divide(&f.monos[0])) {
+                monos.push(mono);
+            }
+            for i in 0..N {
+                degree[i] += 1;
+                if degree[i] < bound[i] {
+                    continue 'outer;
+                }
+                degree[i] = 0;
+            }
+            break;
+        }
+        monos.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        Some(monos)
+    }
+
+    // quotient_dimension
+    // 剰余環R/Iの線形空間としての次元, すなわち標準単項式の数を返す. 0次元でない場合はNoneを返す.
+    pub fn quotient_dimension(&self) -> Option<usize> {
+        self.standard_monomials().map(|monos| monos.len())
+    }
+
+    // multiplication_matrix
+    // R/Iにおけるi番目(0から数える)の変数倍の写像を, 標準単項式を基底として表した行列を返す.
+    // j列目は standard_monomials の j番目の元に x_{i+1} をかけたものの正規形の係数である.
+    // 固有値はV(I)の点のx_{i+1}座標になる. 0次元でない場合はNoneを返す.
+    pub fn multiplication_matrix(&self, i: usize) -> Option<Vec<Vec<F>>> {
+        let monos = self.standard_monomials()?;
+        let mut matrix = vec![vec![F::zero(); monos.len()]; monos.len()];
+        for (j, mono) in monos.iter().enumerate() {
+            let mut degree = mono.degree;
+            degree[i] += 1;
+            let nf = self.reduce(&Polynomial::new(vec![Monomial::new(F::unit(), degree)]));
+            for term in nf.monos.iter() {
+                // 正規形の単項式はすべて標準単項式である.
+                let k = monos.iter().position(|m| m.degree == term.degree).unwrap();
+                matrix[k][j] = term.coef;
+            }
+        }
+        Some(matrix)
+    }
+}
@@ -0,0 +1,518 @@
+use super::elimination::implicitize;
+use super::hilbert::affine_hilbert_series;
+use super::monomial::MonomialIdeal;
+use super::operation::Ideal;
+use super::projective::{affine_part, projective_closure};
+use super::ring::QuotientRing;
+use super::singular::{is_smooth_complete_intersection, is_smooth_hypersurface};
+use crate::algebra::fp;
+use crate::algebra::rational::Rational;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::Univariate;
+
+type Fp = fp::Fp<5>;
+
+fn mono(coef: i32, degree: [u32; 2]) -> Polynomial<Fp, 2> {
+    Polynomial::new(vec![Monomial::new(Fp::new(coef), degree)])
+}
+
+#[test]
+fn sum_product() {
+    let i = Ideal::new(vec![mono(1, [1, 0])]);
+    let j = Ideal::new(vec![mono(3, [0, 1])]);
+    assert_eq!(i.sum(&j).basis, vec![mono(1, [1, 0]), mono(1, [0, 1])]);
+    assert_eq!(i.product(&j).basis, vec![mono(1, [1, 1])]);
+    assert!(i.product(&j).is_subset(&i));
+    assert!(!i.is_subset(&j));
+
+    // (x + 1) + (x) = (1)
+    let k = Ideal::new(vec![mono(1, [1, 0]).add(&mono(1, [0, 0]))]);
+    assert!(i.sum(&k).is_unit());
+    assert!(!i.is_unit());
+}
+
+#[test]
+fn intersection() {
+    let i = Ideal::new(vec![mono(1, [1, 0])]);
+    let j = Ideal::new(vec![mono(1, [0, 1])]);
+    assert_eq!(i.intersection::<3>(&j).basis, vec![mono(1, [1, 1])]);
+
+    // (x^2, y) ∩ (x, y^2) = (x^2, xy, y^2)
+    let i = Ideal::new(vec![mono(1, [2, 0]), mono(1, [0, 1])]);
+    let j = Ideal::new(vec![mono(1, [1, 0]), mono(1, [0, 2])]);
+    assert_eq!(
+        i.intersection::<3>(&j).basis,
+        vec![mono(1, [2, 0]), mono(1, [1, 1]), mono(1, [0, 2])]
+    );
+
+    // (x - 1) ∩ (x + 1) = (x^2 - 1)
+    let i = Ideal::new(vec![mono(1, [1, 0]).sub(&mono(1, [0, 0]))]);
+    let j = Ideal::new(vec![mono(1, [1, 0]).add(&mono(1, [0, 0]))]);
+    let k = i.intersection::<3>(&j);
+    assert_eq!(k, Ideal::new(vec![mono(1, [2, 0]).sub(&mono(1, [0, 0]))]));
+    assert!(k.contains(&mono(1, [3, 0]).sub(&mono(1, [1, 0]))));
+}
+
+#[test]
+fn quotient() {
+    // (x^2, xy) : (x) = (x, y)
+    let i = Ideal::new(vec![mono(1, [2, 0]), mono(1, [1, 1])]);
+    let x = mono(1, [1, 0]);
+    assert_eq!(
+        i.quotient_by_poly::<3>(&x).basis,
+        vec![mono(1, [1, 0]), mono(1, [0, 1])]
+    );
+    // (x^2, xy) : (x, y) = (x)
+    let j = Ideal::new(vec![x.clone(), mono(1, [0, 1])]);
+    assert_eq!(i.quotient::<3>(&j).basis, vec![x.clone()]);
+    assert!(i.quotient::<3>(&i).is_unit());
+}
+
+#[test]
+fn saturation() {
+    // (x^2 y, x y^2) : x^∞ = (y)
+    let i = Ideal::new(vec![mono(1, [2, 1]), mono(1, [1, 2])]);
+    assert_eq!(
+        i.saturation_by_poly::<3>(&mono(1, [1, 0])).basis,
+        vec![mono(1, [0, 1])]
+    );
+
+    // (x(x - 1)) : (x - 1)^∞ = (x)
+    let x_1 = mono(1, [1, 0]).sub(&mono(1, [0, 0]));
+    let i = Ideal::new(vec![mono(1, [1, 0]).mul_poly(&x_1)]);
+    assert_eq!(i.saturation_by_poly::<3>(&x_1).basis, vec![mono(1, [1, 0])]);
+
+    // (x^2, xy) : (x, y)^∞ = (x)
+    let i = Ideal::new(vec![mono(1, [2, 0]), mono(1, [1, 1])]);
+    let m = Ideal::new(vec![mono(1, [1, 0]), mono(1, [0, 1])]);
+    assert_eq!(i.saturation::<3>(&m).basis, vec![mono(1, [1, 0])]);
+}
+
+#[test]
+fn elimination() {
+    // (x_1 - x_2^2, x_2 - x_3) から x_2 を消去すると (x_1 - x_3^2)
+    let var = |i| Polynomial::<Fp, 3>::variable(i);
+    let i = Ideal::new(vec![
+        var(0).sub(&var(1).mul_poly(&var(1))),
+        var(1).sub(&var(2)),
+    ]);
+    assert_eq!(
+        i.eliminate(&[1]),
+        Ideal::new(vec![var(0).sub(&var(2).mul_poly(&var(2)))])
+    );
+    assert_eq!(i.eliminate(&[]), i);
+    assert!(i.eliminate(&[0, 1, 2]).is_zero());
+    assert_eq!(i.eliminate(&[1, 1]), i.eliminate(&[1]));
+}
+
+#[test]
+fn implicitization() {
+    // (t^2, t^3) の像は y^2 = x^3
+    let t = Polynomial::<Fp, 1>::variable(0);
+    let i = implicitize::<Fp, 1, 2, 3>(&[t.mul_poly(&t), t.mul_poly(&t).mul_poly(&t)]);
+    assert_eq!(i.basis, vec![mono(1, [3, 0]).sub(&mono(1, [0, 2]))]);
+}
+
+#[test]
+fn singular_locus() {
+    let x = Polynomial::<Fp, 2>::variable(0);
+    let y = Polynomial::<Fp, 2>::variable(1);
+    let one = Polynomial::constant(Fp::new(1));
+    // カスプ y^2 = x^3 の特異点は原点のみ (ヤコビイデアルは根基とは限らない)
+    let cusp = y.mul_poly(&y).sub(&x.mul_poly(&x).mul_poly(&x));
+    assert_eq!(
+        Ideal::new(vec![cusp.clone()]).singular_locus().basis,
+        vec![mono(1, [2, 0]), mono(1, [0, 1])]
+    );
+    assert!(!is_smooth_hypersurface(&cusp));
+    assert!(Ideal::new(vec![one.clone()]).singular_locus().is_unit());
+    // 円 x^2 + y^2 = 1 は滑らか
+    assert!(is_smooth_hypersurface(
+        &x.mul_poly(&x).add(&y.mul_poly(&y)).sub(&one)
+    ));
+    // x^5 = y は x についての微分が0になるが滑らか
+    let frobenius = (0..5).fold(one.clone(), |acc, _| acc.mul_poly(&x)).sub(&y);
+    assert!(is_smooth_hypersurface(&frobenius));
+
+    // 空間曲線 (x - y^2, z - xy) は滑らか, (z, y^2 - x^3) は原点が特異点
+    let v = |i| Polynomial::<Fp, 3>::variable(i);
+    assert!(is_smooth_complete_intersection(&[
+        v(0).sub(&v(1).mul_poly(&v(1))),
+        v(2).sub(&v(0).mul_poly(&v(1))),
+    ]));
+    let singular = [
+        v(2),
+        v(1).mul_poly(&v(1))
+            .sub(&v(0).mul_poly(&v(0)).mul_poly(&v(0))),
+    ];
+    assert!(!is_smooth_complete_intersection(&singular));
+    assert_eq!(
+        Ideal::new(singular.to_vec()).singular_locus(),
+        Ideal::new(vec![v(0).mul_poly(&v(0)), v(1), v(2)])
+    );
+}
+
+#[test]
+fn projective_closure_test() {
+    // ねじれ3次曲線 (y - x^2, z - x^3) の射影閉包は2次式 wy - x^2, wz - xy, xz - y^2 で定まる
+    let v = |i| Polynomial::<Fp, 3>::variable(i);
+    let cubic = Ideal::new(vec![
+        v(1).sub(&v(0).mul_poly(&v(0))),
+        v(2).sub(&v(0).mul_poly(&v(0)).mul_poly(&v(0))),
+    ]);
+    let closure = projective_closure::<Fp, 3, 4, 5>(&cubic);
+    let w = |i| Polynomial::<Fp, 4>::variable(i);
+    let minor =
+        |a: usize, b: usize, c: usize, d: usize| w(a).mul_poly(&w(b)).sub(&w(c).mul_poly(&w(d)));
+    assert_eq!(
+        closure,
+        Ideal::new(vec![
+            minor(0, 2, 1, 1),
+            minor(0, 3, 1, 2),
+            minor(1, 3, 2, 2)
+        ])
+    );
+    assert!(closure.basis.iter().all(|f| f.is_homogeneous()));
+    assert_eq!(affine_part::<Fp, 4, 3>(&closure), cubic);
+}
+
+#[test]
+fn quotient_ring() {
+    // F_5[x, y]/(x^2 - 2, y - x - 1) は F_25 と同型
+    let x = mono(1, [1, 0]);
+    let y = mono(1, [0, 1]);
+    let c = |c| mono(c, [0, 0]);
+    let ring = QuotientRing::new(vec![x.mul_poly(&x).sub(&c(2)), y.sub(&x).sub(&c(1))]);
+    let (rx, ry) = (ring.element(&x), ring.element(&y));
+    assert_eq!(rx.mul(&rx), ring.element(&c(2)));
+    assert_eq!(ry, rx.add(&ring.unit()));
+    assert_eq!(rx.pow(4), ring.element(&c(4)));
+    // F_25^* の位数は24
+    assert_eq!(ry.pow(24), ring.unit());
+    let inv = ry.inverse::<3>().unwrap();
+    assert_eq!(inv.mul(&ry), ring.unit());
+    assert!(ring.zero().inverse::<3>().is_none());
+
+    // F_5[x, y]/(x^2 - 1, y) では x - 1 は零因子なので逆元を持たない
+    let ring = QuotientRing::new(vec![x.mul_poly(&x).sub(&c(1)), y.clone()]);
+    assert!(ring.element(&x.sub(&c(1))).inverse::<3>().is_none());
+    assert_eq!(ring.element(&x).inverse::<3>(), Some(ring.element(&x)));
+    assert!(ring
+        .element(&x.sub(&c(1)))
+        .mul(&ring.element(&x.add(&c(1))))
+        .is_zero());
+
+    // 同じイデアルでも別の環の元は等しくない
+    let other = QuotientRing::new(vec![x.mul_poly(&x).sub(&c(1)), y.clone()]);
+    assert_ne!(ring.element(&x), other.element(&x));
+}
+
+#[test]
+#[should_panic(expected = "residues of different rings")]
+fn residues_of_different_rings() {
+    let x = mono(1, [1, 0]);
+    let r = QuotientRing::new(vec![x.mul_poly(&x)]);
+    let s = QuotientRing::new(vec![x.mul_poly(&x).mul_poly(&x)]);
+    let _ = r.element(&x).mul(&s.element(&x));
+}
+
+#[test]
+fn standard_monomials() {
+    // (x^2 - 2, y - x - 1) の簡約グレブナー基底は (x - y + 1, y^2 - 2y - 1) で, 標準単項式は y, 1
+    let x = mono(1, [1, 0]);
+    let y = mono(1, [0, 1]);
+    let c = |c| mono(c, [0, 0]);
+    let ideal = Ideal::new(vec![x.mul_poly(&x).sub(&c(2)), y.sub(&x).sub(&c(1))]);
+    let degrees: Vec<[u32; 2]> = ideal
+        .standard_monomials()
+        .unwrap()
+        .iter()
+        .map(|mono| mono.degree)
+        .collect();
+    assert_eq!(degrees, vec![[0, 1], [0, 0]]);
+    assert_eq!(ideal.quotient_dimension(), Some(2));
+    let f = |c: i32| Fp::new(c);
+    let mx = ideal.multiplication_matrix(0).unwrap();
+    let my = ideal.multiplication_matrix(1).unwrap();
+    assert_eq!(mx, vec![vec![f(1), f(1)], vec![f(1), f(4)]]);
+    assert_eq!(my, vec![vec![f(2), f(1)], vec![f(1), f(0)]]);
+
+    // (x^2, xy, y^3) の標準単項式は x, y^2, y, 1
+    let ideal = Ideal::new(vec![mono(1, [2, 0]), mono(1, [1, 1]), mono(1, [0, 3])]);
+    assert_eq!(ideal.quotient_dimension(), Some(4));
+    // (xy) は0次元でない
+    assert_eq!(Ideal::new(vec![mono(1, [1, 1])]).standard_monomials(), None);
+    assert_eq!(Ideal::new(vec![c(1)]).quotient_dimension(), Some(0));
+}
+
+#[test]
+fn dimension() {
+    // (xy) は2直線の和で1次元, 極大独立集合は {x}, {y}
+    let ideal = Ideal::new(vec![mono(1, [1, 1])]);
+    assert_eq!(ideal.dimension(), Some(1));
+    assert_eq!(ideal.maximal_independent_sets(), vec![vec![0], vec![1]]);
+    // (x^2, xy) は直線 x = 0 と原点の埋没成分
+    let ideal = Ideal::new(vec![mono(1, [2, 0]), mono(1, [1, 1])]);
+    assert_eq!(ideal.independent_set(), Some(vec![1]));
+    assert!(!ideal.is_independent(&[0]));
+    // 0次元, 全体, 空
+    let ideal = Ideal::new(vec![mono(1, [2, 0]).sub(&mono(1, [0, 0])), mono(1, [0, 1])]);
+    assert_eq!(ideal.dimension(), Some(0));
+    assert_eq!(ideal.maximal_independent_sets(), vec![Vec::<usize>::new()]);
+    assert!(ideal.is_zero_dimensional());
+    assert_eq!(Ideal::<Fp, 2>::new(vec![]).dimension(), Some(2));
+    assert_eq!(Ideal::new(vec![mono(2, [0, 0])]).dimension(), None);
+    assert!(!Ideal::new(vec![mono(2, [0, 0])]).is_zero_dimensional());
+    assert!(!Ideal::new(vec![mono(1, [1, 1])]).is_zero_dimensional());
+
+    // ねじれ3次曲線は1次元
+    let v = |i| Polynomial::<Fp, 3>::variable(i);
+    let cubic = Ideal::new(vec![
+        v(1).sub(&v(0).mul_poly(&v(0))),
+        v(2).sub(&v(0).mul_poly(&v(0)).mul_poly(&v(0))),
+    ]);
+    assert_eq!(cubic.dimension(), Some(1));
+}
+
+#[test]
+fn hilbert_series() {
+    // 射影空間のねじれ3次曲線: (1 + 2t) / (1-t)^2, ヒルベルト多項式 3s + 1
+    let w = |i| Polynomial::<Fp, 4>::variable(i);
+    let minor =
+        |a: usize, b: usize, c: usize, d: usize| w(a).mul_poly(&w(b)).sub(&w(c).mul_poly(&w(d)));
+    let cubic = Ideal::new(vec![
+        minor(0, 2, 1, 1),
+        minor(0, 3, 1, 2),
+        minor(1, 3, 2, 2),
+    ]);
+    let series = cubic.hilbert_series().unwrap();
+    assert_eq!(series.reduced(), (vec![1, 2], 2));
+    assert_eq!((series.dimension(), series.degree()), (2, 3));
+    assert_eq!(
+        series.hilbert_polynomial(),
+        Univariate::new(vec![Rational::new(1, 1), Rational::new(3, 1)])
+    );
+    assert_eq!(
+        (0..5).map(|s| series.value(s)).collect::<Vec<_>>(),
+        vec![1, 4, 7, 10, 13]
+    );
+
+    // 2次曲線 x^2 + y^2 - 1 は斉次でないが, 付随する次数つき環の次元は1, 次数は2
+    let x = mono(1, [1, 0]);
+    let y = mono(1, [0, 1]);
+    let conic = Ideal::new(vec![x
+        .mul_poly(&x)
+        .add(&y.mul_poly(&y))
+        .sub(&mono(1, [0, 0]))]);
+    assert_eq!(conic.hilbert_series(), None);
+    let series = affine_hilbert_series::<Fp, 2, 3, 4>(&conic);
+    assert_eq!((series.dimension(), series.degree()), (1, 2));
+    // 0次元イデアルの次数は R/I の次元に等しい
+    let points = Ideal::new(vec![x.mul_poly(&x).sub(&mono(2, [0, 0])), y.sub(&x)]);
+    let series = affine_hilbert_series::<Fp, 2, 3, 4>(&points);
+    assert_eq!((series.dimension(), series.degree()), (0, 2));
+    assert_eq!(series.hilbert_polynomial(), Univariate::zero());
+    let unit = Ideal::new(vec![mono(1, [0, 0])]).hilbert_series().unwrap();
+    assert_eq!((unit.dimension(), unit.degree(), unit.value(3)), (0, 0, 0));
+}
+
+#[test]
+fn monomial_ideal() {
+    let ideal = |gens: &[[u32; 3]]| MonomialIdeal::new(gens.to_vec());
+    let i = ideal(&[[1, 1, 0], [0, 1, 1], [2, 1, 0]]);
+    assert_eq!(i.gens, vec![[1, 1, 0], [0, 1, 1]]);
+    assert!(i.contains(&[1, 2, 1]));
+    assert!(!i.contains(&[1, 0, 5]));
+    let j = ideal(&[[1, 0, 0], [0, 0, 1]]);
+    assert_eq!(i.sum(&j), ideal(&[[1, 0, 0], [0, 0, 1]]));
+    assert_eq!(i.product(&j), ideal(&[[2, 1, 0], [1, 1, 1], [0, 1, 2]]));
+    assert_eq!(i.intersection(&j), i);
+    assert_eq!(i.quotient(&j), ideal(&[[0, 1, 0]]));
+    assert_eq!(j.quotient_by_monomial(&[1, 0, 0]), ideal(&[[0, 0, 0]]));
+
+    // (x^2, xy, y^3) = (x, y^3) ∩ (x^2, y)
+    let k = ideal(&[[2, 0, 0], [1, 1, 0], [0, 3, 0]]);
+    assert_eq!(
+        k.irreducible_decomposition(),
+        vec![
+            ideal(&[[2, 0, 0], [0, 1, 0]]),
+            ideal(&[[1, 0, 0], [0, 3, 0]])
+        ]
+    );
+    let components = i.irreducible_decomposition();
+    assert_eq!(
+        components,
+        vec![ideal(&[[1, 0, 0], [0, 0, 1]]), ideal(&[[0, 1, 0]])]
+    );
+    assert_eq!(components[0].intersection(&components[1]), i);
+
+    // (xy, yz) のアレクサンダー双対は (y, xz)
+    assert_eq!(i.alexander_dual(&[1, 1, 1]), ideal(&[[1, 0, 1], [0, 1, 0]]));
+    assert_eq!(i.alexander_dual(&[1, 1, 1]).alexander_dual(&[1, 1, 1]), i);
+
+    // R/(x^2, xy, y^3) のヒルベルト級数は (1 + 2t + t^2) / (1-t) なので, 分子は (1-t)^2 (1+t)^2
+    let series = k.hilbert_series();
+    assert_eq!(series.numerator, vec![1, 0, -2, 0, 1]);
+    assert_eq!((series.dimension(), series.degree()), (1, 4));
+    assert_eq!(ideal(&[[0, 0, 0]]).hilbert_numerator(), Vec::<i64>::new());
+    assert_eq!(ideal(&[]).hilbert_numerator(), vec![1]);
+
+    // 先頭単項式のイデアル
+    let f = Ideal::new(vec![mono(1, [2, 0]).sub(&mono(1, [0, 1])), mono(1, [1, 1])]);
+    assert_eq!(f.leading_ideal().gens, vec![[2, 0], [1, 1], [0, 2]]);
+}
+
+#[test]
+fn radical() {
+    let x = mono(1, [1, 0]);
+    let y = mono(1, [0, 1]);
+    let c = |c| mono(c, [0, 0]);
+    // (x^2, y^3) の根基は (x, y)
+    let i = Ideal::new(vec![mono(1, [2, 0]), mono(1, [0, 3])]);
+    assert!(i.radical_contains::<3>(&x.add(&y)));
+    assert!(!i.contains(&x.add(&y)));
+    assert!(!i.radical_contains::<3>(&x.sub(&c(1))));
+    assert_eq!(i.radical::<3>(), Ok(Ideal::new(vec![x.clone(), y.clone()])));
+    assert_eq!(i.is_radical::<3>(), Ok(false));
+
+    // ((x - 1)^2 (x - 2), y^2 - x) の根基は ((x - 1)(x - 2), y^2 - x)
+    let x1 = x.sub(&c(1));
+    let x2 = x.sub(&c(2));
+    let i = Ideal::new(vec![x1.mul_poly(&x1).mul_poly(&x2), y.mul_poly(&y).sub(&x)]);
+    let rad = Ideal::new(vec![x1.mul_poly(&x2), y.mul_poly(&y).sub(&x)]);
+    assert_eq!(i.radical::<3>(), Ok(rad.clone()));
+    assert_eq!(rad.is_radical::<3>(), Ok(true));
+
+    // 0次元でない場合も根基への所属は判定できる
+    let i = Ideal::new(vec![mono(1, [2, 1])]);
+    assert!(i.radical_contains::<3>(&mono(1, [1, 1])));
+    assert!(!i.radical_contains::<3>(&x));
+    assert!(i.radical::<3>().is_err());
+}
+
+#[test]
+fn primary_decomposition() {
+    let x = mono(1, [1, 0]);
+    let y = mono(1, [0, 1]);
+    let c = |c| mono(c, [0, 0]);
+    let decompose = |gens: Vec<Polynomial<Fp, 2>>| {
+        let ideal = Ideal::new(gens);
+        let components = ideal.primary_decomposition::<3>().unwrap();
+        let intersection = components
+            .iter()
+            .skip(1)
+            .fold(components[0].primary.clone(), |acc, q| {
+                acc.intersection::<3>(&q.primary)
+            });
+        assert_eq!(intersection, ideal);
+        components
+            .into_iter()
+            .map(|q| (q.primary, q.prime))
+            .collect::<Vec<_>>()
+    };
+
+    // (x^2 (x - 1), y - x) = (x^2, y - x) ∩ (x - 1, y - 1)
+    let components = decompose(vec![x.mul_poly(&x).mul_poly(&x.sub(&c(1))), y.sub(&x)]);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&(
+        Ideal::new(vec![mono(1, [2, 0]), y.sub(&x)]),
+        Ideal::new(vec![x.clone(), y.clone()])
+    )));
+    let point = Ideal::new(vec![x.sub(&c(1)), y.sub(&c(1))]);
+    assert!(components.contains(&(point.clone(), point)));
+
+    // F_5上で 2 は平方剰余でないので (x^2 - 2, y^2 - 2) = (x^2 - 2, y - x) ∩ (x^2 - 2, y + x)
+    // どちらの変数の消去イデアルも既約だが, 2つの成分に分かれる.
+    let square = x.mul_poly(&x).sub(&c(2));
+    let components = decompose(vec![square.clone(), y.mul_poly(&y).sub(&c(2))]);
+    let minus = Ideal::new(vec![square.clone(), y.sub(&x)]);
+    let plus = Ideal::new(vec![square, y.add(&x)]);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&(minus.clone(), minus)));
+    assert!(components.contains(&(plus.clone(), plus)));
+
+    // 単項式イデアル (x^2, xy) = (x) ∩ (x^2, y), (x, y) は埋没素イデアル
+    let components = decompose(vec![mono(1, [2, 0]), mono(1, [1, 1])]);
+    assert_eq!(
+        components,
+        vec![
+            (
+                Ideal::new(vec![mono(1, [2, 0]), y.clone()]),
+                Ideal::new(vec![x.clone(), y.clone()])
+            ),
+            (Ideal::new(vec![x.clone()]), Ideal::new(vec![x.clone()])),
+        ]
+    );
+
+    // 正次元: F_5上で (xy(x - 1)) = (x) ∩ (x - 1) ∩ (y)
+    let components = decompose(vec![x.mul_poly(&y).mul_poly(&x.sub(&c(1)))]);
+    assert_eq!(components.len(), 3);
+    for g in [x.clone(), x.sub(&c(1)), y.clone()] {
+        let prime = Ideal::new(vec![g]);
+        assert!(components.contains(&(prime.clone(), prime)));
+    }
+    // (x^5 - y) は素イデアル. F_5(y) 上で x^5 - y は非分離だが既約である.
+    let frobenius = Ideal::new(vec![mono(1, [5, 0]).sub(&y)]);
+    assert_eq!(
+        decompose(frobenius.basis.clone()),
+        vec![(frobenius.clone(), frobenius)]
+    );
+    // (xy - 1) は素イデアル
+    let hyperbola = Ideal::new(vec![x.mul_poly(&y).sub(&c(1))]);
+    assert_eq!(
+        decompose(hyperbola.basis.clone()),
+        vec![(hyperbola.clone(), hyperbola)]
+    );
+    // (x^2, x(y - 1)) = (x) ∩ (x^2, y - 1), (x, y - 1) は埋没素イデアル
+    let components = decompose(vec![mono(1, [2, 0]), x.mul_poly(&y.sub(&c(1)))]);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&(Ideal::new(vec![x.clone()]), Ideal::new(vec![x.clone()]))));
+    assert!(components.contains(&(
+        Ideal::new(vec![mono(1, [2, 0]), y.sub(&c(1))]),
+        Ideal::new(vec![x.clone(), y.sub(&c(1))])
+    )));
+
+    // Q上
+    let q = |n, d: [u32; 2]| {
+        Polynomial::<Rational, 2>::new(vec![Monomial::new(Rational::new(n, 1), d)])
+    };
+    let (qx, qy, two) = (q(1, [1, 0]), q(1, [0, 1]), q(2, [0, 0]));
+    let decompose = |gens: Vec<Polynomial<Rational, 2>>| {
+        let ideal = Ideal::new(gens);
+        let components = ideal.primary_decomposition::<3>().unwrap();
+        let intersection = components
+            .iter()
+            .skip(1)
+            .fold(components[0].primary.clone(), |acc, q| {
+                acc.intersection::<3>(&q.primary)
+            });
+        assert_eq!(intersection, ideal);
+        components
+            .into_iter()
+            .map(|q| (q.primary, q.prime))
+            .collect::<Vec<_>>()
+    };
+    // (x^2 - 2, y^2 - 2) = (x^2 - 2, y - x) ∩ (x^2 - 2, y + x): 座標変換をしないと一般の位置にない
+    let square = qx.mul_poly(&qx).sub(&two);
+    let components = decompose(vec![square.clone(), qy.mul_poly(&qy).sub(&two)]);
+    let minus = Ideal::new(vec![square.clone(), qy.sub(&qx)]);
+    let plus = Ideal::new(vec![square.clone(), qy.add(&qx)]);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&(minus.clone(), minus)));
+    assert!(components.contains(&(plus.clone(), plus)));
+    // (y (x^2 - 2)^2) = (y) ∩ ((x^2 - 2)^2)
+    let components = decompose(vec![qy.mul_poly(&square).mul_poly(&square)]);
+    assert_eq!(components.len(), 2);
+    assert!(components.contains(&(Ideal::new(vec![qy.clone()]), Ideal::new(vec![qy.clone()]))));
+    assert!(components.contains(&(
+        Ideal::new(vec![square.mul_poly(&square)]),
+        Ideal::new(vec![square.clone()])
+    )));
+}
+
+#[test]
+#[should_panic(expected = "variable index out of range")]
+fn elimination_out_of_range() {
+    Ideal::new(vec![mono(1, [1, 0])]).eliminate(&[2]);
+}
@@ -1,4 +1,8 @@
 pub mod algebra;
 pub mod buchberger;
 pub mod equation_parser;
+pub mod factorization;
+pub mod ideal;
 pub mod polynomial;
+pub mod repl;
+pub mod solver;
@@ -1,38 +1,54 @@
-use algebra::{algebra::fp, buchberger::grobner, equation_parser::parser, polynomial::poly};
+use algebra::{
+    algebra::fp, buchberger::grobner, equation_parser::parser, polynomial::poly, repl::shell,
+};
 use clap::Parser;
+use std::io;
 
 const P: i32 = 7;
 const N: usize = 3;
 
 #[derive(Parser)]
 struct Args {
-    #[clap(short = 'e', long = "equation")]
-    equation: String,
+    #[clap(
+        short = 'e',
+        long = "equation",
+        required_unless_present = "interactive"
+    )]
+    equation: Option<String>,
+    #[clap(short = 'i', long = "interactive")]
+    interactive: bool,
+    #[clap(long = "explain")]
+    explain: bool,
+    #[clap(long = "markdown", requires = "explain")]
+    markdown: bool,
 }
 
 fn main() {
     let args = Args::parse();
-    let polys: Vec<poly::Polynomial<fp::Fp<P>, N>> = match parser::parse(&args.equation) {
+    if args.interactive {
+        let shell = shell::Shell::new(P, N).unwrap();
+        if let Err(err) = shell::run(shell, io::stdin().lock(), io::stdout()) {
+            panic!("{}", err);
+        }
+        return;
+    }
+    let polys: Vec<poly::Polynomial<fp::Fp<P>, N>> = match parser::parse(&args.equation.unwrap()) {
         Ok(polys) => polys,
         Err(msg) => panic!("{}", msg),
     };
-    println!(
-        "Original Ideal: ({})",
-        polys
-            .iter()
-            .map(|x| format!("{}", x))
-            .collect::<Vec<String>>()
-            .join(", ")
-    );
-    let grobner = grobner::buchberger(polys);
+    println!("Original Ideal: ({})", poly::join(&polys));
+    let grobner = if args.explain {
+        let (grobner, trace) = grobner::buchberger_traced(polys);
+        if args.markdown {
+            println!("{}", trace.to_markdown());
+        } else {
+            println!("{}", trace.to_text());
+        }
+        grobner
+    } else {
+        grobner::buchberger(polys)
+    };
     let minimal = grobner::minimal_grobner(grobner);
     let simple = grobner::simplified_grobner(minimal);
-    println!(
-        "Simplified Grobner Basis: {}",
-        simple
-            .iter()
-            .map(|x| format!("{}", x))
-            .collect::<Vec<String>>()
-            .join(", ")
-    );
+    println!("Simplified Grobner Basis: {}", poly::join(&simple));
 }
@@ -0,0 +1,377 @@
+use super::monomial::Monomial;
+use super::poly::Polynomial;
+use super::univariate::{coefficients_in, embed, Univariate};
+use crate::algebra::field::{self, Field};
+use crate::algebra::fp::Fp;
+use crate::algebra::gcd::{chinese_remainder, rational_reconstruction};
+use crate::algebra::rational::Rational;
+use crate::ideal::operation::Ideal;
+use std::collections::BTreeMap;
+
+// lcm
+// 多項式の最小公倍数を返す. 先頭係数は1になるように正規化する.
+// 単項イデアルの共通部分 (f) ∩ (g) は単項イデアル (lcm(f, g)) なので, その簡約グレブナー基底として求める.
+// 共通部分の計算に変数を1つ加えるため M = N+1 とする.
+pub fn lcm<F, const N: usize, const M: usize>(
+    f: &Polynomial<F, N>,
+    g: &Polynomial<F, N>,
+) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    if f.is_zero() || g.is_zero() {
+        return Polynomial::new(vec![]);
+    }
+    let mut basis = Ideal::new(vec![f.clone()])
+        .intersection::<M>(&Ideal::new(vec![g.clone()]))
+        .basis;
+    basis.pop().unwrap()
+}
+
+// Gcd
+// 多変数多項式の最大公約数を計算できる係数体.
+// F_Pでは評価と補間(gcd_modular), Qでは法pの最大公約数からの復元(gcd_rational)を用いる.
+pub trait Gcd: Field {
+    fn gcd<const N: usize, const M: usize>(
+        f: &Polynomial<Self, N>,
+        g: &Polynomial<Self, N>,
+    ) -> Result<Polynomial<Self, N>, &'static str>;
+}
+
+impl<const P: i32> Gcd for Fp<P> {
+    fn gcd<const N: usize, const M: usize>(
+        f: &Polynomial<Fp<P>, N>,
+        g: &Polynomial<Fp<P>, N>,
+    ) -> Result<Polynomial<Fp<P>, N>, &'static str> {
+        Ok(gcd_modular::<P, N, M>(f, g))
+    }
+}
+
+impl Gcd for Rational {
+    fn gcd<const N: usize, const M: usize>(
+        f: &Polynomial<Rational, N>,
+        g: &Polynomial<Rational, N>,
+    ) -> Result<Polynomial<Rational, N>, &'static str> {
+        gcd_rational::<N, M>(f, g)
+    }
+}
+
+// gcd
+// 多項式の最大公約数を返す. 先頭係数は1になるように正規化する. 計算方法は係数体ごとに Gcd で選ぶ.
+// F_Pの点が足りない場合に最小公倍数から求めるため M = N+1 とする.
+pub fn gcd<F, const N: usize, const M: usize>(
+    f: &Polynomial<F, N>,
+    g: &Polynomial<F, N>,
+) -> Result<Polynomial<F, N>, &'static str>
+where
+    F: Gcd,
+{
+    F::gcd::<N, M>(f, g)
+}
+
+// gcd_by_lcm
+// fg = gcd(f, g) lcm(f, g) を用いて最大公約数を求める. グレブナー基底の計算に変数を1つ加える.
+fn gcd_by_lcm<F, const N: usize, const M: usize>(
+    f: &Polynomial<F, N>,
+    g: &Polynomial<F, N>,
+) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    let mut ans = if f.is_zero() {
+        g.clone()
+    } else if g.is_zero() {
+        f.clone()
+    } else {
+        f.mul_poly(g).div_exact(&lcm::<F, N, M>(f, g)).unwrap()
+    };
+    if !ans.is_zero() {
+        ans.norm();
+    }
+    ans
+}
+
+// gcd_modular
+// F_P上の最大公約数を, 変数に値を代入して変数の少ない最大公約数を求め, 補間して求める(ブラウンのアルゴリズム).
+// 補間に使える点が足りない小さいPでは gcd_by_lcm で求める.
+fn gcd_modular<const P: i32, const N: usize, const M: usize>(
+    f: &Polynomial<Fp<P>, N>,
+    g: &Polynomial<Fp<P>, N>,
+) -> Polynomial<Fp<P>, N> {
+    if f.is_zero() || g.is_zero() {
+        return gcd_by_lcm::<Fp<P>, N, M>(f, g);
+    }
+    brown(f, g, N).unwrap_or_else(|| gcd_by_lcm::<Fp<P>, N, M>(f, g))
+}
+
+// brown
+// 0でないf, gの最大公約数を先頭係数を1にして返す. fとgはx_1, ..., x_varsのみを含むとする.
+// x_varsを係数とみなして内容(係数の最大公約数)と原始的な部分に分け, x_vars = a を代入した原始的な部分の
+// 最大公約数を再帰的に求める. 先頭係数の最大公約数γ(a)をかけた像をニュートン補間でつなぎ,
+// 点の数がx_varsについての次数の上限を超えるか補間結果が変わらなくなったら, その原始的な部分がf, gを
+// 割り切るか確かめる. 先頭単項式が他より大きい像は不運な点なので捨てる.
+// 点が足りない場合はNoneを返す.
+fn brown<const P: i32, const N: usize>(
+    f: &Polynomial<Fp<P>, N>,
+    g: &Polynomial<Fp<P>, N>,
+    vars: usize,
+) -> Option<Polynomial<Fp<P>, N>> {
+    if vars == 0 {
+        return Some(Polynomial::constant(Fp::unit()));
+    }
+    let v = vars - 1;
+    if v == 0 {
+        let h = Univariate::from_variable(f, 0).gcd(&Univariate::from_variable(g, 0));
+        return Some(embed(&h, 0, [0; N]));
+    }
+    let (cf, pf) = content(f, v);
+    let (cg, pg) = content(g, v);
+    let c = embed(&cf.gcd(&cg), v, [0; N]);
+    let lcf = lead(&pf, v);
+    let lcg = lead(&pg, v);
+    let gamma = lcf.gcd(&lcg);
+    let degree = |h: &Polynomial<Fp<P>, N>| h.monos.iter().map(|mono| mono.degree[v]).max();
+    let bound = gamma.degree() + degree(&pf)?.min(degree(&pg)?) as usize;
+    // γ gcd(f, g) を x_v 以外の次数ごとの x_v の1変数多項式として補間する.
+    let mut interpolated: BTreeMap<[u32; N], Univariate<Fp<P>>> = BTreeMap::new();
+    let mut lead_degree = None;
+    let mut modulus = Univariate::constant(Fp::unit());
+    let mut count = 0;
+    for a in 0..P {
+        let a = Fp::new(a);
+        if lcf.eval(a) == Fp::zero() || lcg.eval(a) == Fp::zero() {
+            continue;
+        }
+        let image = brown(&pf.partial_eval(&[(v, a)]), &pg.partial_eval(&[(v, a)]), v)?;
+        let image_degree = image.monos[0].degree;
+        if image_degree == [0; N] {
+            return Some(c);
+        }
+        match lead_degree {
+            Some(d) if image_degree > d => continue,
+            Some(d) if image_degree == d => {}
+            _ => {
+                interpolated.clear();
+                modulus = Univariate::constant(Fp::unit());
+                count = 0;
+                lead_degree = Some(image_degree);
+            }
+        }
+        // H ← H + (γ(a) image - H(a)) m / m(a), m = Π (x_v - a_i)
+        let scale = gamma.eval(a);
+        let weight = modulus.scale(Fp::unit() / modulus.eval(a));
+        let mut degrees: Vec<[u32; N]> = interpolated.keys().copied().collect();
+        degrees.extend(image.monos.iter().map(|mono| mono.degree));
+        let mut changed = false;
+        for alpha in degrees {
+            let value = image
+                .monos
+                .iter()
+                .find(|mono| mono.degree == alpha)
+                .map_or(Fp::zero(), |mono| mono.coef);
+            let entry = interpolated.entry(alpha).or_insert_with(Univariate::zero);
+            let diff = scale * value - entry.eval(a);
+            if diff != Fp::zero() {
+                *entry = entry.add(&weight.scale(diff));
+                changed = true;
+            }
+        }
+        modulus = modulus.mul(&Univariate::new(vec![-a, Fp::unit()]));
+        count += 1;
+        if count > bound || !changed {
+            let h = interpolated
+                .iter()
+                .fold(Polynomial::new(vec![]), |acc, (alpha, u)| {
+                    acc.add(&embed(u, v, *alpha))
+                });
+            let (_, h) = content(&h, v);
+            if pf.div_exact(&h).is_some() && pg.div_exact(&h).is_some() {
+                let mut ans = h.mul_poly(&c);
+                ans.norm();
+                return Some(ans);
+            }
+        }
+    }
+    None
+}
+
+// content
+// x_kを係数とみなしたときの内容(係数の最大公約数, モニック)と原始的な部分を返す.
+fn content<F, const N: usize>(
+    poly: &Polynomial<F, N>,
+    k: usize,
+) -> (Univariate<F>, Polynomial<F, N>)
+where
+    F: field::Field,
+{
+    let coefs = coefficients_in(poly, k);
+    let content = coefs.values().fold(Univariate::zero(), |acc, u| acc.gcd(u));
+    let primitive = coefs
+        .iter()
+        .fold(Polynomial::new(vec![]), |acc, (alpha, u)| {
+            acc.add(&embed(&u.div_rem(&content).0, k, *alpha))
+        });
+    (content, primitive)
+}
+
+// lead
+// x_kを係数とみなしたときの先頭係数を, x_kの1変数多項式として返す.
+fn lead<F, const N: usize>(poly: &Polynomial<F, N>, k: usize) -> Univariate<F>
+where
+    F: field::Field,
+{
+    coefficients_in(poly, k)
+        .pop_last()
+        .map_or(Univariate::zero(), |(_, u)| u)
+}
+
+// ModularImage
+// 法pでの最大公約数. pと, 単項式の次数と0以上p未満の係数の組を単項式順序で大きいものから並べたもの.
+type ModularImage<const N: usize> = (i32, Vec<([u32; N], i32)>);
+
+// gcd_rational
+// Q上の最大公約数を, 素数pごとに求めた F_p 上の最大公約数から復元する(ブラウンのアルゴリズム).
+// fとgの先頭係数がpで割り切れなければ, 法pの最大公約数の先頭単項式は真の最大公約数のもの以上になる.
+// 先頭単項式が最小の像だけを中国剰余定理で合わせ, 有理数再構成の結果が前の素数と変わらなければ,
+// それがfとgを割り切るか確かめる. 割り切れば先頭単項式が真のもの以上の公約数なので最大公約数である.
+// 法はi128に収まる約2^96までなので, 先頭係数を1にした最大公約数の係数の分子と分母の絶対値が
+// およそ2^47を超えると復元できない. その場合や用意した素数で足りない場合はエラーを返す.
+fn gcd_rational<const N: usize, const M: usize>(
+    f: &Polynomial<Rational, N>,
+    g: &Polynomial<Rational, N>,
+) -> Result<Polynomial<Rational, N>, &'static str> {
+    if f.is_zero() || g.is_zero() {
+        return Ok(gcd_by_lcm::<Rational, N, M>(f, g));
+    }
+    type Image<const N: usize> =
+        fn(&Polynomial<Rational, N>, &Polynomial<Rational, N>) -> Option<ModularImage<N>>;
+    let images: [Image<N>; 16] = [
+        modular_gcd::<46337, N, M>,
+        modular_gcd::<46327, N, M>,
+        modular_gcd::<46309, N, M>,
+        modular_gcd::<46307, N, M>,
+        modular_gcd::<46301, N, M>,
+        modular_gcd::<46279, N, M>,
+        modular_gcd::<46273, N, M>,
+        modular_gcd::<46271, N, M>,
+        modular_gcd::<46261, N, M>,
+        modular_gcd::<46237, N, M>,
+        modular_gcd::<46229, N, M>,
+        modular_gcd::<46219, N, M>,
+        modular_gcd::<46199, N, M>,
+        modular_gcd::<46187, N, M>,
+        modular_gcd::<46183, N, M>,
+        modular_gcd::<46181, N, M>,
+    ];
+    // 係数を法modulusで表したもの. 単項式順序で大きいものから並ぶ.
+    let mut coefs: Vec<([u32; N], i128)> = Vec::new();
+    let mut modulus: i128 = 1;
+    let mut prev: Option<Polynomial<Rational, N>> = None;
+    for image in images.iter() {
+        let (p, image) = match image(f, g) {
+            Some(image) => image,
+            None => continue,
+        };
+        if image[0].0 == [0; N] {
+            return Ok(Polynomial::constant(Rational::new(1, 1)));
+        }
+        if coefs.is_empty() || image[0].0 < coefs[0].0 {
+            coefs.clear();
+            modulus = 1;
+            prev = None;
+        } else if image[0].0 > coefs[0].0 {
+            continue;
+        }
+        if modulus.checked_mul(p as i128 * p as i128).is_none() {
+            break;
+        }
+        let mut degrees: Vec<[u32; N]> = coefs
+            .iter()
+            .map(|(degree, _)| *degree)
+            .chain(image.iter().map(|(degree, _)| *degree))
+            .collect();
+        degrees.sort_by(|a, b| b.cmp(a));
+        degrees.dedup();
+        let find = |list: &[([u32; N], i128)], degree: &[u32; N]| {
+            list.iter()
+                .find(|(d, _)| d == degree)
+                .map_or(0, |(_, c)| *c)
+        };
+        let image: Vec<([u32; N], i128)> = image.iter().map(|&(d, c)| (d, c as i128)).collect();
+        coefs = degrees
+            .iter()
+            .map(|degree| {
+                let c = chinese_remainder(find(&coefs, degree), modulus, find(&image, degree), p);
+                (*degree, c)
+            })
+            .collect();
+        modulus *= p as i128;
+        let candidate = match reconstruct(&coefs, modulus) {
+            Some(candidate) => candidate,
+            None => continue,
+        };
+        if prev.as_ref() == Some(&candidate)
+            && f.div_exact(&candidate).is_some()
+            && g.div_exact(&candidate).is_some()
+        {
+            return Ok(candidate);
+        }
+        prev = Some(candidate);
+    }
+    Err("coefficients of the gcd are too large to reconstruct")
+}
+
+// modular_gcd
+// fとgを法Pで還元して最大公約数を求める. 素数Pは46341未満とする. 先頭係数か分母がPで割り切れる場合はNoneを返す.
+fn modular_gcd<const P: i32, const N: usize, const M: usize>(
+    f: &Polynomial<Rational, N>,
+    g: &Polynomial<Rational, N>,
+) -> Option<ModularImage<N>> {
+    let f = reduce_mod::<P, N>(f)?;
+    let g = reduce_mod::<P, N>(g)?;
+    let h = gcd_modular::<P, N, M>(&f, &g);
+    Some((
+        P,
+        h.monos
+            .iter()
+            .map(|mono| (mono.degree, mono.coef.val()))
+            .collect(),
+    ))
+}
+
+fn reduce_mod<const P: i32, const N: usize>(
+    f: &Polynomial<Rational, N>,
+) -> Option<Polynomial<Fp<P>, N>> {
+    let p = P as i128;
+    let mut monos = Vec::new();
+    for mono in f.monos.iter() {
+        let den = mono.coef.den().rem_euclid(p);
+        if den == 0 {
+            return None;
+        }
+        let coef = Fp::<P>::new(mono.coef.num().rem_euclid(p) as i32) / Fp::new(den as i32);
+        monos.push(Monomial::new(coef, mono.degree));
+    }
+    if monos[0].is_zero() {
+        return None;
+    }
+    monos.retain(|mono| !mono.is_zero());
+    Some(Polynomial::new(monos))
+}
+
+// reconstruct
+// 法modulusの係数を有理数再構成で有理数に戻す. 1つでも失敗すればNoneを返す.
+fn reconstruct<const N: usize>(
+    coefs: &[([u32; N], i128)],
+    modulus: i128,
+) -> Option<Polynomial<Rational, N>> {
+    let mut monos = Vec::new();
+    for &(degree, c) in coefs.iter() {
+        if c == 0 {
+            continue;
+        }
+        let (num, den) = rational_reconstruction(c, modulus)?;
+        monos.push(Monomial::new(Rational::from_i128(num, den), degree));
+    }
+    Some(Polynomial::new(monos))
+}
@@ -1,5 +1,7 @@
+pub mod gcd;
 pub mod monomial;
 pub mod poly;
+pub mod univariate;
 
 #[cfg(test)]
 mod test;
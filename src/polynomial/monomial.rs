@@ -45,13 +45,19 @@ where
         }
     }
 
+    // total_degree
+    // 全次数, すなわち各変数の次数の和を返す.
+    pub fn total_degree(&self) -> u32 {
+        self.degree.iter().sum()
+    }
+
     pub fn can_divide(&self, rhs: &Monomial<F, N>) -> bool {
         for i in 0..N {
             if self.degree[i] < rhs.degree[i] {
                 return false;
             }
         }
-        return true;
+        true
     }
 
     // div
@@ -22,10 +22,93 @@ where
         Polynomial { monos }
     }
 
+    // constant
+    // 定数cを表す多項式を返す.
+    pub fn constant(c: F) -> Polynomial<F, N> {
+        let mut poly = Polynomial::new(vec![Monomial::new(c, [0; N])]);
+        poly.monos.retain(|mono| !mono.is_zero());
+        poly
+    }
+
+    // variable
+    // i番目(0から数える)の変数x_{i+1}を表す多項式を返す.
+    pub fn variable(i: usize) -> Polynomial<F, N> {
+        let mut degree = [0; N];
+        degree[i] = 1;
+        Polynomial::new(vec![Monomial::new(F::unit(), degree)])
+    }
+
     pub fn is_zero(&self) -> bool {
         self.monos.is_empty()
     }
 
+    // total_degree
+    // 単項式の全次数の最大値を返す. 0多項式に対しては0を返す.
+    pub fn total_degree(&self) -> u32 {
+        self.monos
+            .iter()
+            .map(|mono| mono.total_degree())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // is_homogeneous
+    // すべての単項式の全次数が等しいか判定する. 0多項式は斉次とみなす.
+    pub fn is_homogeneous(&self) -> bool {
+        self.monos
+            .iter()
+            .all(|mono| mono.total_degree() == self.monos[0].total_degree())
+    }
+
+    // permute
+    // i番目の変数をperm[i]番目の変数に置き換えた多項式を返す. permは0, ..., N-1の並べ替えであること.
+    pub fn permute(&self, perm: &[usize; N]) -> Polynomial<F, N> {
+        let monos = self
+            .monos
+            .iter()
+            .map(|mono| {
+                let mut degree = [0; N];
+                for (i, &deg) in mono.degree.iter().enumerate() {
+                    degree[perm[i]] = deg;
+                }
+                Monomial::new(mono.coef, degree)
+            })
+            .collect();
+        let mut poly = Polynomial::new(monos);
+        poly.arrange();
+        poly
+    }
+
+    // arrange
+    // 単項式を単項式順序で大きいものから並べ, 次数が同じ単項式をまとめ, 係数が0の単項式を取り除く.
+    // 入力された多項式が順序を保っているとは限らないときに呼ぶ.
+    pub fn arrange(&mut self) {
+        self.monos.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let mut monos: Vec<Monomial<F, N>> = Vec::with_capacity(self.monos.len());
+        for mono in self.monos.drain(..) {
+            match monos.last_mut() {
+                Some(last) if *last == mono => last.coef = last.coef + mono.coef,
+                _ => monos.push(mono),
+            }
+        }
+        monos.retain(|mono| !mono.is_zero());
+        self.monos = monos;
+    }
+
+    // neg
+    // -selfを返す.
+    pub fn neg(&self) -> Polynomial<F, N> {
+        Polynomial {
+            monos: self.monos.iter().map(|mono| mono.neg()).collect(),
+        }
+    }
+
+    // add
+    // rhsを足した結果を返す.
+    pub fn add(&self, rhs: &Polynomial<F, N>) -> Polynomial<F, N> {
+        self.sub(&rhs.neg())
+    }
+
     // sub
     // rhsを引いた結果を返す.
     pub fn sub(&self, rhs: &Polynomial<F, N>) -> Polynomial<F, N> {
@@ -73,13 +156,140 @@ where
         Polynomial { monos }
     }
 
+    // mul_poly
+    // 多項式をかける.
+    pub fn mul_poly(&self, rhs: &Polynomial<F, N>) -> Polynomial<F, N> {
+        let mut ans = Polynomial::new(vec![]);
+        for mono in rhs.monos.iter() {
+            ans = ans.add(&self.mul(mono));
+        }
+        ans
+    }
+
+    // eval
+    // 点pointでの値を返す.
+    pub fn eval(&self, point: &[F; N]) -> F {
+        let mut ans = F::zero();
+        for mono in self.monos.iter() {
+            let mut term = mono.coef;
+            for (i, &deg) in mono.degree.iter().enumerate() {
+                for _ in 0..deg {
+                    term = term * point[i];
+                }
+            }
+            ans = ans + term;
+        }
+        ans
+    }
+
+    // partial_eval
+    // values に含まれる (i, a) について, i番目(0から数える)の変数にaを代入した多項式を返す.
+    // 代入した変数は現れなくなるが, 変数の数はNのままである.
+    pub fn partial_eval(&self, values: &[(usize, F)]) -> Polynomial<F, N> {
+        let monos = self
+            .monos
+            .iter()
+            .map(|mono| {
+                let mut coef = mono.coef;
+                let mut degree = mono.degree;
+                for &(i, a) in values.iter() {
+                    for _ in 0..degree[i] {
+                        coef = coef * a;
+                    }
+                    degree[i] = 0;
+                }
+                Monomial::new(coef, degree)
+            })
+            .collect();
+        let mut poly = Polynomial::new(monos);
+        poly.arrange();
+        poly
+    }
+
+    // substitute
+    // i番目の変数にpolys[i]を代入した多項式, すなわち合成を返す. 代入する多項式はM変数でもよい.
+    pub fn substitute<const M: usize>(&self, polys: &[Polynomial<F, M>; N]) -> Polynomial<F, M> {
+        // powers[i][d] = polys[i]^d を必要になった分だけ計算しておく.
+        let mut powers: Vec<Vec<Polynomial<F, M>>> = vec![vec![Polynomial::constant(F::unit())]; N];
+        let mut ans = Polynomial::new(vec![]);
+        for mono in self.monos.iter() {
+            let mut term = Polynomial::constant(mono.coef);
+            for (i, &deg) in mono.degree.iter().enumerate() {
+                while powers[i].len() <= deg as usize {
+                    let next = powers[i].last().unwrap().mul_poly(&polys[i]);
+                    powers[i].push(next);
+                }
+                term = term.mul_poly(&powers[i][deg as usize]);
+            }
+            ans = ans.add(&term);
+        }
+        ans
+    }
+
+    // derivative
+    // i番目(0から数える)の変数についての形式的な偏微分を返す.
+    // 係数に次数をかけるときは体の元として計算するので, 正標数では係数が0になる項を取り除く.
+    pub fn derivative(&self, i: usize) -> Polynomial<F, N> {
+        Polynomial::new(
+            self.monos
+                .iter()
+                .filter(|mono| mono.degree[i] > 0)
+                .map(|mono| {
+                    let mut degree = mono.degree;
+                    degree[i] -= 1;
+                    Monomial::new(F::from_usize(mono.degree[i] as usize) * mono.coef, degree)
+                })
+                .filter(|mono| !mono.is_zero())
+                .collect(),
+        )
+    }
+
+    // gradient
+    // 各変数についての偏微分を並べたものを返す.
+    pub fn gradient(&self) -> [Polynomial<F, N>; N] {
+        std::array::from_fn(|i| self.derivative(i))
+    }
+
+    // div_rem
+    // rhsで割った商と余りを返す. 余りのどの項もrhsの先頭単項式で割り切れない.
+    // rhsで割り切れる場合は余りが0になる. rhsが0でないことを確認してから呼ぶこと.
+    pub fn div_rem(&self, rhs: &Polynomial<F, N>) -> (Polynomial<F, N>, Polynomial<F, N>) {
+        let mut quo = Vec::new();
+        let mut rem = Vec::new();
+        let mut poly = self.clone();
+        while !poly.is_zero() {
+            if poly.monos[0].can_divide(&rhs.monos[0]) {
+                let mono = poly.monos[0].div(&rhs.monos[0]);
+                poly = poly.sub(&rhs.mul(&mono));
+                quo.push(mono);
+            } else {
+                rem.push(poly.monos.remove(0));
+            }
+        }
+        (Polynomial::new(quo), Polynomial::new(rem))
+    }
+
+    // div_exact
+    // rhsで割り切れる場合は商を返し, 割り切れない場合はNoneを返す.
+    pub fn div_exact(&self, rhs: &Polynomial<F, N>) -> Option<Polynomial<F, N>> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (quo, rem) = self.div_rem(rhs);
+        if rem.is_zero() {
+            Some(quo)
+        } else {
+            None
+        }
+    }
+
     pub fn norm(&mut self) {
         if self.monos[0].coef == F::unit() || self.monos[0].coef == F::zero() {
             return;
         }
         let lc = self.monos[0].coef;
         for mono in self.monos.iter_mut() {
-            (*mono).coef = (*mono).coef / lc;
+            mono.coef = mono.coef / lc;
         }
     }
 
@@ -137,6 +347,28 @@ where
     }
 }
 
+// show
+// 多項式を表示用の文字列にする. 0多項式は"0"と表示する.
+pub fn show<F, const N: usize>(poly: &Polynomial<F, N>) -> String
+where
+    F: field::Field + fmt::Display,
+{
+    if poly.is_zero() {
+        "0".to_string()
+    } else {
+        format!("{}", poly)
+    }
+}
+
+// join
+// 多項式の列をshowで表示し, ", "で区切って並べる.
+pub fn join<F, const N: usize>(polys: &[Polynomial<F, N>]) -> String
+where
+    F: field::Field + fmt::Display,
+{
+    polys.iter().map(show).collect::<Vec<String>>().join(", ")
+}
+
 // simplify
 // 多項式polyを多項式の集合polysで簡約化する.
 // もし簡約化できなければfalseとpolyをそのまま返す, 簡約化できれば簡約化したものを返す.
@@ -163,3 +395,99 @@ where
     }
     (simplifed_once, poly)
 }
+
+// jacobian
+// 多項式の列polysのヤコビ行列を返す. i行目はpolys[i]の勾配である.
+pub fn jacobian<F, const N: usize>(polys: &[Polynomial<F, N>]) -> Vec<[Polynomial<F, N>; N]>
+where
+    F: field::Field,
+{
+    polys.iter().map(|poly| poly.gradient()).collect()
+}
+
+// extend
+// N変数の多項式を, 先頭にM-N個の変数を加えたM変数の多項式とみなす.
+// 加えた変数は単項式順序で既存の変数より大きいので, 単項式の順序は保たれる.
+pub fn extend<F, const N: usize, const M: usize>(poly: &Polynomial<F, N>) -> Polynomial<F, M>
+where
+    F: field::Field,
+{
+    assert!(M >= N);
+    let monos = poly
+        .monos
+        .iter()
+        .map(|mono| {
+            let mut degree = [0; M];
+            degree[M - N..].copy_from_slice(&mono.degree);
+            Monomial::new(mono.coef, degree)
+        })
+        .collect();
+    Polynomial::new(monos)
+}
+
+// restrict
+// 先頭のM-N個の変数を含まないM変数の多項式をN変数の多項式とみなす.
+// 先頭の変数を含む場合はNoneを返す.
+pub fn restrict<F, const M: usize, const N: usize>(
+    poly: &Polynomial<F, M>,
+) -> Option<Polynomial<F, N>>
+where
+    F: field::Field,
+{
+    assert!(M >= N);
+    let mut monos = Vec::with_capacity(poly.monos.len());
+    for mono in poly.monos.iter() {
+        if mono.degree[..M - N].iter().any(|&deg| deg != 0) {
+            return None;
+        }
+        let mut degree = [0; N];
+        degree.copy_from_slice(&mono.degree[M - N..]);
+        monos.push(Monomial::new(mono.coef, degree));
+    }
+    Some(Polynomial::new(monos))
+}
+
+// homogenize
+// N変数の多項式を, 先頭に変数x_0を加えたM = N+1変数の斉次多項式にする.
+// 各単項式に x_0^(全次数 - 単項式の次数) をかける.
+pub fn homogenize<F, const N: usize, const M: usize>(poly: &Polynomial<F, N>) -> Polynomial<F, M>
+where
+    F: field::Field,
+{
+    assert_eq!(M, N + 1, "homogenization needs one extra variable");
+    let d = poly.total_degree();
+    let mut ans = Polynomial::new(
+        poly.monos
+            .iter()
+            .map(|mono| {
+                let mut degree = [0; M];
+                degree[0] = d - mono.total_degree();
+                degree[1..].copy_from_slice(&mono.degree);
+                Monomial::new(mono.coef, degree)
+            })
+            .collect(),
+    );
+    ans.arrange();
+    ans
+}
+
+// dehomogenize
+// M = N+1変数の多項式の先頭の変数x_0に1を代入し, N変数の多項式にする.
+pub fn dehomogenize<F, const M: usize, const N: usize>(poly: &Polynomial<F, M>) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    assert_eq!(M, N + 1, "dehomogenization removes one variable");
+    let mut ans = Polynomial::new(
+        poly.monos
+            .iter()
+            .map(|mono| {
+                let mut degree = [0; N];
+                degree.copy_from_slice(&mono.degree[1..]);
+                Monomial::new(mono.coef, degree)
+            })
+            .collect(),
+    );
+    ans.arrange();
+    ans
+}
@@ -1,9 +1,12 @@
 use super::{
+    gcd::{self, gcd},
     monomial::lcm,
     monomial::Monomial,
-    poly::{simplify, Polynomial},
+    poly::{dehomogenize, homogenize, jacobian, simplify, Polynomial},
+    univariate::Univariate,
 };
 use crate::algebra::fp;
+use crate::algebra::rational::Rational;
 
 type Fp = fp::Fp<7>;
 
@@ -85,3 +88,272 @@ fn poly_simp() {
         ])
     );
 }
+
+#[test]
+fn div_rem() {
+    // (x^2 + 2xy + 3) = (x + 2y)x + 3
+    let poly = Polynomial::new(vec![
+        Monomial::new(Fp::new(1), [2, 0]),
+        Monomial::new(Fp::new(2), [1, 1]),
+        Monomial::new(Fp::new(3), [0, 0]),
+    ]);
+    let divisor = Polynomial::new(vec![
+        Monomial::new(Fp::new(1), [1, 0]),
+        Monomial::new(Fp::new(2), [0, 1]),
+    ]);
+    let (quo, rem) = poly.div_rem(&divisor);
+    assert_eq!(
+        quo,
+        Polynomial::new(vec![Monomial::new(Fp::new(1), [1, 0])])
+    );
+    assert_eq!(rem, Polynomial::constant(Fp::new(3)));
+    assert_eq!(divisor.mul_poly(&quo).add(&rem), poly);
+}
+
+#[test]
+fn univariate_roots() {
+    // (x - 1)^2 (x - 3)(x - 5)(x^2 + 1) over F_7
+    let linear = |a: i32| Univariate::new(vec![-Fp::new(a), Fp::new(1)]);
+    let f = linear(1)
+        .mul(&linear(1))
+        .mul(&linear(3))
+        .mul(&linear(5))
+        .mul(&Univariate::new(vec![Fp::new(1), Fp::new(0), Fp::new(1)]));
+    let mut roots = f.roots();
+    roots.sort_by_key(|a| format!("{}", a));
+    assert_eq!(roots, vec![Fp::new(1), Fp::new(3), Fp::new(5)]);
+    assert_eq!(f.eval(Fp::new(3)), Fp::new(0));
+
+    let (quo, rem) = f.div_rem(&linear(3));
+    assert!(rem.is_zero());
+    assert_eq!(quo.degree(), 5);
+    assert_eq!(f.gcd(&linear(1).mul(&linear(2))), linear(1));
+
+    let g = Univariate::<fp::Fp<2>>::new(vec![fp::Fp::new(0), fp::Fp::new(1), fp::Fp::new(1)]);
+    assert_eq!(g.roots().len(), 2);
+}
+
+#[test]
+fn univariate_euclid() {
+    let u = |coefs: &[i32]| Univariate::new(coefs.iter().map(|&c| Fp::new(c)).collect());
+    // f = x^2 + 1, g = x + 3 over F_7
+    let f = u(&[1, 0, 1]);
+    let g = u(&[3, 1]);
+    let (d, s, t) = f.ext_gcd(&g);
+    assert_eq!(d, u(&[1]));
+    assert_eq!(s.mul(&f).add(&t.mul(&g)), d);
+    let inv = g.inverse_mod(&f).unwrap();
+    assert_eq!(inv.mul(&g).div_rem(&f).1, u(&[1]));
+    assert_eq!(u(&[1, 1]).inverse_mod(&u(&[1, 2, 1])), None);
+
+    // Res(f, g) = f(-3) = 10 = 3, disc(x^2 + 1) = -4 = 3
+    assert_eq!(f.resultant(&g), Fp::new(3));
+    assert_eq!(g.resultant(&f), Fp::new(3));
+    assert_eq!(f.discriminant(), Fp::new(3));
+    // x^3 + x + 1 の判別式は -4 - 27 = -31 = 4
+    assert_eq!(u(&[1, 1, 0, 1]).discriminant(), Fp::new(4));
+    assert_eq!(u(&[1, 2, 1]).discriminant(), Fp::new(0));
+
+    assert_eq!(u(&[1, 2, 0, 4]).derivative(), u(&[2, 0, 12]));
+    assert_eq!(f.compose(&g), u(&[10, 6, 1]));
+    assert_eq!(f.eval(Fp::new(2)), Fp::new(5));
+
+    let poly = f.to_poly();
+    assert_eq!(
+        poly,
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [2]),
+            Monomial::new(Fp::new(1), [0]),
+        ])
+    );
+    assert_eq!(Univariate::from_poly(&poly), f);
+    // x_2^2 + 1 を x_2 の1変数多項式とみなす
+    let poly = Polynomial::new(vec![
+        Monomial::new(Fp::new(1), [0, 2]),
+        Monomial::new(Fp::new(1), [0, 0]),
+    ]);
+    assert_eq!(Univariate::from_variable(&poly, 1), f);
+    assert_eq!(format!("{}", f), "x_1^2+1");
+}
+
+#[test]
+fn multivariate_gcd() {
+    // f = (x + y)(x - 2y), g = (x + y)^2 y
+    let x = Polynomial::<Fp, 2>::variable(0);
+    let y = Polynomial::<Fp, 2>::variable(1);
+    let x_plus_y = x.add(&y);
+    let f = x_plus_y.mul_poly(&x.sub(&y).sub(&y));
+    let g = x_plus_y.mul_poly(&x_plus_y).mul_poly(&y);
+    assert_eq!(gcd::<Fp, 2, 3>(&f, &g), Ok(x_plus_y.clone()));
+    let l = gcd::lcm::<Fp, 2, 3>(&f, &g);
+    assert_eq!(l.div_exact(&f), Some(x_plus_y.mul_poly(&y)));
+    assert_eq!(f.div_exact(&y), None);
+    assert_eq!(
+        gcd::<Fp, 2, 3>(&x, &y),
+        Ok(Polynomial::constant(Fp::new(1)))
+    );
+
+    // Q上で 2x^2 - 2 と 3x + 3 の最大公約数は x + 1
+    let q = |num, den| Rational::new(num, den);
+    let x = Polynomial::<Rational, 1>::variable(0);
+    let f = x.mul_poly(&x).sub(&Polynomial::constant(q(1, 1)));
+    let f = f.mul(&Monomial::new(q(2, 1), [0]));
+    let g = x.add(&Polynomial::constant(q(1, 1)));
+    let g = g.mul(&Monomial::new(q(3, 1), [0]));
+    assert_eq!(
+        gcd::<Rational, 1, 2>(&f, &g),
+        Ok(x.add(&Polynomial::constant(q(1, 1))))
+    );
+
+    // F_7上で (x z + y^2)(y + z)(z^2 + 1) と (x z + y^2)(x + y z)(z^2 + 1)^2: x_3 についての内容を含む
+    let v = |i| Polynomial::<Fp, 3>::variable(i);
+    let c = |c| Polynomial::<Fp, 3>::constant(Fp::new(c));
+    let h = v(0).mul_poly(&v(2)).add(&v(1).mul_poly(&v(1)));
+    let content = v(2).mul_poly(&v(2)).add(&c(1));
+    let common = h.mul_poly(&content);
+    let f = common.mul_poly(&v(1).add(&v(2)));
+    let g = common
+        .mul_poly(&v(0).add(&v(1).mul_poly(&v(2))))
+        .mul_poly(&content);
+    let mut expected = common.clone();
+    expected.norm();
+    assert_eq!(gcd::<Fp, 3, 4>(&f, &g), Ok(expected));
+
+    // F_2のように点の少ない体でも求められる
+    let v = |i| Polynomial::<fp::Fp<2>, 2>::variable(i);
+    let one = Polynomial::<fp::Fp<2>, 2>::constant(fp::Fp::new(1));
+    let h = v(0).mul_poly(&v(1)).add(&one);
+    let f = h.mul_poly(&v(0).add(&v(1)));
+    let g = h.mul_poly(&h).mul_poly(&v(1).mul_poly(&v(1)).add(&v(0)));
+    assert_eq!(gcd::<fp::Fp<2>, 2, 3>(&f, &g), Ok(h));
+}
+
+#[test]
+fn rational_gcd_large_coefficients() {
+    // h = 1234x + 977y^2 + 4567 を共通因子に持つ f = ha, g = hb.
+    // グレブナー基底を経由するとi128が溢れる大きさだが, 法pの計算から復元できる.
+    let q = |c| Polynomial::<Rational, 2>::constant(Rational::new(c, 1));
+    let x = Polynomial::<Rational, 2>::variable(0);
+    let y = Polynomial::<Rational, 2>::variable(1);
+    let h = q(1234)
+        .mul_poly(&x)
+        .add(&q(977).mul_poly(&y.mul_poly(&y)))
+        .add(&q(4567));
+    let a = q(2345)
+        .mul_poly(&x.mul_poly(&x).mul_poly(&x))
+        .add(&q(6789).mul_poly(&x.mul_poly(&y).mul_poly(&y)))
+        .add(&q(1011).mul_poly(&y))
+        .add(&q(3141));
+    let b = q(5926)
+        .mul_poly(&x.mul_poly(&x).mul_poly(&y))
+        .add(&q(5358).mul_poly(&y.mul_poly(&y).mul_poly(&y)))
+        .add(&q(9793).mul_poly(&x))
+        .add(&q(2384));
+    let f = h.mul_poly(&a);
+    let g = h.mul_poly(&b);
+    let mut monic = h.clone();
+    monic.norm();
+    assert_eq!(gcd::<Rational, 2, 3>(&f, &g), Ok(monic));
+    assert_eq!(
+        gcd::<Rational, 2, 3>(&a, &b),
+        Ok(Polynomial::constant(Rational::new(1, 1)))
+    );
+
+    // 先頭係数を1にした最大公約数 x + 2^50 + 1 の係数は法pから復元できる大きさを超える
+    let h = x.add(&q((1 << 50) + 1));
+    let f = h.mul_poly(&x.add(&q(1)));
+    let g = h.mul_poly(&y);
+    assert!(gcd::<Rational, 2, 3>(&f, &g).is_err());
+}
+
+#[test]
+fn evaluation() {
+    // f = 3x^2 y + 2y + 1
+    let x = Polynomial::<Fp, 2>::variable(0);
+    let y = Polynomial::<Fp, 2>::variable(1);
+    let one = Polynomial::constant(Fp::new(1));
+    let f = x
+        .mul_poly(&x)
+        .mul_poly(&y)
+        .mul(&Monomial::new(Fp::new(3), [0, 0]))
+        .add(&y.add(&y))
+        .add(&one);
+    assert_eq!(f.eval(&[Fp::new(2), Fp::new(3)]), Fp::new(1));
+    // x = 1 を代入すると 5y + 1
+    assert_eq!(
+        f.partial_eval(&[(0, Fp::new(1))]),
+        y.mul(&Monomial::new(Fp::new(5), [0, 0])).add(&one)
+    );
+    // y = x + 1 を代入すると 3x^3 + 3x^2 + 2x + 3
+    assert_eq!(
+        f.substitute(&[x.clone(), x.add(&one)]),
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(3), [3, 0]),
+            Monomial::new(Fp::new(3), [2, 0]),
+            Monomial::new(Fp::new(2), [1, 0]),
+            Monomial::new(Fp::new(3), [0, 0]),
+        ])
+    );
+    // 1変数 t に (t^2, t) を代入すると 3t^5 + 2t + 1
+    let t = Polynomial::<Fp, 1>::variable(0);
+    assert_eq!(
+        f.substitute(&[t.mul_poly(&t), t.clone()]),
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(3), [5]),
+            Monomial::new(Fp::new(2), [1]),
+            Monomial::new(Fp::new(1), [0]),
+        ])
+    );
+}
+
+#[test]
+fn derivative() {
+    // f = x^7 y + 3x^2 y^2 + y over F_7
+    let f = Polynomial::new(vec![
+        Monomial::new(Fp::new(1), [7, 1]),
+        Monomial::new(Fp::new(3), [2, 2]),
+        Monomial::new(Fp::new(1), [0, 1]),
+    ]);
+    // ∂f/∂x = 7x^6 y + 6xy^2 = 6xy^2
+    assert_eq!(
+        f.derivative(0),
+        Polynomial::new(vec![Monomial::new(Fp::new(6), [1, 2])])
+    );
+    let [fx, fy] = f.gradient();
+    assert_eq!(fx, f.derivative(0));
+    assert_eq!(
+        fy,
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [7, 0]),
+            Monomial::new(Fp::new(6), [2, 1]),
+            Monomial::new(Fp::new(1), [0, 0]),
+        ])
+    );
+
+    let g = Polynomial::<Fp, 2>::variable(0).mul_poly(&Polynomial::variable(1));
+    let jac = jacobian(&[f, g]);
+    assert_eq!(jac.len(), 2);
+    assert_eq!(jac[1], [Polynomial::variable(1), Polynomial::variable(0)]);
+}
+
+#[test]
+fn homogenization() {
+    // x^2 y + 3x + 1 -> x^2 y + 3 w^2 x + w^3
+    let f = Polynomial::<Fp, 2>::new(vec![
+        Monomial::new(Fp::new(1), [2, 1]),
+        Monomial::new(Fp::new(3), [1, 0]),
+        Monomial::new(Fp::new(1), [0, 0]),
+    ]);
+    let g = homogenize::<Fp, 2, 3>(&f);
+    assert_eq!(
+        g,
+        Polynomial::new(vec![
+            Monomial::new(Fp::new(1), [3, 0, 0]),
+            Monomial::new(Fp::new(3), [2, 1, 0]),
+            Monomial::new(Fp::new(1), [0, 2, 1]),
+        ])
+    );
+    assert!(g.is_homogeneous());
+    assert!(!f.is_homogeneous());
+    assert_eq!(dehomogenize::<Fp, 3, 2>(&g), f);
+}
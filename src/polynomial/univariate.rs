@@ -0,0 +1,402 @@
+use super::monomial::Monomial;
+use super::poly::Polynomial;
+use crate::algebra::field::{self, Field};
+use crate::algebra::fp::Fp;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Univariate
+// 係数体をFとする1変数多項式を表す構造体.
+// coefs[i]はx^iの係数で, 最高次の係数が0にならないように保つ. 0多項式ではcoefsは空である.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Univariate<F>
+where
+    F: field::Field,
+{
+    pub coefs: Vec<F>,
+}
+
+impl<F> Univariate<F>
+where
+    F: field::Field,
+{
+    pub fn new(mut coefs: Vec<F>) -> Univariate<F> {
+        while coefs.last() == Some(&F::zero()) {
+            coefs.pop();
+        }
+        Univariate { coefs }
+    }
+
+    pub fn zero() -> Univariate<F> {
+        Univariate { coefs: vec![] }
+    }
+
+    pub fn constant(c: F) -> Univariate<F> {
+        Univariate::new(vec![c])
+    }
+
+    // x
+    // 変数xを表す多項式を返す.
+    pub fn x() -> Univariate<F> {
+        Univariate::new(vec![F::zero(), F::unit()])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+
+    // degree
+    // 次数を返す. 0多項式に対しては0を返す.
+    pub fn degree(&self) -> usize {
+        self.coefs.len().saturating_sub(1)
+    }
+
+    // lead
+    // 最高次の係数を返す. 0多項式に対しては0を返す.
+    pub fn lead(&self) -> F {
+        self.coefs.last().copied().unwrap_or_else(F::zero)
+    }
+
+    pub fn neg(&self) -> Univariate<F> {
+        Univariate {
+            coefs: self.coefs.iter().map(|&c| -c).collect(),
+        }
+    }
+
+    pub fn add(&self, rhs: &Univariate<F>) -> Univariate<F> {
+        let len = self.coefs.len().max(rhs.coefs.len());
+        let coef = |p: &Univariate<F>, i: usize| p.coefs.get(i).copied().unwrap_or_else(F::zero);
+        Univariate::new((0..len).map(|i| coef(self, i) + coef(rhs, i)).collect())
+    }
+
+    pub fn sub(&self, rhs: &Univariate<F>) -> Univariate<F> {
+        self.add(&rhs.neg())
+    }
+
+    pub fn mul(&self, rhs: &Univariate<F>) -> Univariate<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return Univariate::zero();
+        }
+        let mut coefs = vec![F::zero(); self.coefs.len() + rhs.coefs.len() - 1];
+        for (i, &a) in self.coefs.iter().enumerate() {
+            for (j, &b) in rhs.coefs.iter().enumerate() {
+                coefs[i + j] = coefs[i + j] + a * b;
+            }
+        }
+        Univariate::new(coefs)
+    }
+
+    // scale
+    // 各係数にcをかける.
+    pub fn scale(&self, c: F) -> Univariate<F> {
+        Univariate::new(self.coefs.iter().map(|&a| a * c).collect())
+    }
+
+    // monic
+    // 最高次の係数で割ってモニックにする. 0多項式はそのまま返す.
+    pub fn monic(&self) -> Univariate<F> {
+        if self.is_zero() {
+            return Univariate::zero();
+        }
+        self.scale(F::unit() / self.lead())
+    }
+
+    // div_rem
+    // rhsで割った商と余りを返す. rhsが0でないことを確認してから呼ぶこと.
+    pub fn div_rem(&self, rhs: &Univariate<F>) -> (Univariate<F>, Univariate<F>) {
+        if self.coefs.len() < rhs.coefs.len() {
+            return (Univariate::zero(), self.clone());
+        }
+        let lc = rhs.lead();
+        let mut rem = self.coefs.clone();
+        let mut quo = vec![F::zero(); self.coefs.len() - rhs.coefs.len() + 1];
+        for i in (0..quo.len()).rev() {
+            let c = rem[i + rhs.coefs.len() - 1] / lc;
+            quo[i] = c;
+            for (j, &b) in rhs.coefs.iter().enumerate() {
+                rem[i + j] = rem[i + j] - c * b;
+            }
+        }
+        rem.truncate(rhs.coefs.len() - 1);
+        (Univariate::new(quo), Univariate::new(rem))
+    }
+
+    // gcd
+    // モニックな最大公約数を返す. どちらも0のときは0を返す.
+    pub fn gcd(&self, rhs: &Univariate<F>) -> Univariate<F> {
+        let mut a = self.clone();
+        let mut b = rhs.clone();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    // pow_mod
+    // self^exp を modulus で割った余りを返す.
+    pub fn pow_mod(&self, mut exp: usize, modulus: &Univariate<F>) -> Univariate<F> {
+        let mut base = self.div_rem(modulus).1;
+        let mut ans = Univariate::constant(F::unit()).div_rem(modulus).1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans = ans.mul(&base).div_rem(modulus).1;
+            }
+            base = base.mul(&base).div_rem(modulus).1;
+            exp >>= 1;
+        }
+        ans
+    }
+
+    // ext_gcd
+    // モニックな最大公約数gと s*self + t*rhs = g を満たす(s, t)を返す.
+    pub fn ext_gcd(&self, rhs: &Univariate<F>) -> (Univariate<F>, Univariate<F>, Univariate<F>) {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        let (mut sa, mut sb) = (Univariate::constant(F::unit()), Univariate::zero());
+        let (mut ta, mut tb) = (Univariate::zero(), Univariate::constant(F::unit()));
+        while !b.is_zero() {
+            let (q, r) = a.div_rem(&b);
+            let s = sa.sub(&q.mul(&sb));
+            let t = ta.sub(&q.mul(&tb));
+            a = b;
+            b = r;
+            sa = sb;
+            sb = s;
+            ta = tb;
+            tb = t;
+        }
+        if a.is_zero() {
+            return (a, sa, ta);
+        }
+        let inv = F::unit() / a.lead();
+        (a.scale(inv), sa.scale(inv), ta.scale(inv))
+    }
+
+    // inverse_mod
+    // modulusを法とする逆元を返す. modulusと互いに素でない場合はNoneを返す.
+    pub fn inverse_mod(&self, modulus: &Univariate<F>) -> Option<Univariate<F>> {
+        let (g, s, _) = self.ext_gcd(modulus);
+        if g.degree() == 0 && !g.is_zero() {
+            Some(s.div_rem(modulus).1)
+        } else {
+            None
+        }
+    }
+
+    // resultant
+    // 終結式 Res(self, rhs) を返す. 次数はそれぞれ実際の次数を用いる.
+    // self = q*rhs + r, deg self = m, deg rhs = n, deg r = k のとき
+    // Res(self, rhs) = (-1)^{mn} lc(rhs)^{m-k} Res(rhs, r) であることを用いる.
+    pub fn resultant(&self, rhs: &Univariate<F>) -> F {
+        if self.is_zero() || rhs.is_zero() {
+            return F::zero();
+        }
+        let (m, n) = (self.degree(), rhs.degree());
+        if n == 0 {
+            return pow(rhs.lead(), m);
+        }
+        let (_, r) = self.div_rem(rhs);
+        if r.is_zero() {
+            return F::zero();
+        }
+        let ans = pow(rhs.lead(), m - r.degree()) * rhs.resultant(&r);
+        if m * n % 2 == 1 {
+            -ans
+        } else {
+            ans
+        }
+    }
+
+    // discriminant
+    // 判別式 (-1)^{n(n-1)/2} Res(f, f') / lc(f) を返す. ただしf'の次数はn-1とみなす.
+    // 正標数でf'の次数が下がる場合もlc(f)のべきで補正する.
+    pub fn discriminant(&self) -> F {
+        let n = self.degree();
+        if n == 0 {
+            return F::unit();
+        }
+        let derivative = self.derivative();
+        if derivative.is_zero() {
+            return F::zero();
+        }
+        let lc = self.lead();
+        let ans = self.resultant(&derivative) * pow(lc, n - 1 - derivative.degree()) / lc;
+        if n * (n - 1) / 2 % 2 == 1 {
+            -ans
+        } else {
+            ans
+        }
+    }
+
+    // derivative
+    // 形式的な微分を返す.
+    pub fn derivative(&self) -> Univariate<F> {
+        Univariate::new(
+            self.coefs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| F::from_usize(i) * c)
+                .collect(),
+        )
+    }
+
+    // eval
+    // xに値を代入する.
+    pub fn eval(&self, x: F) -> F {
+        self.coefs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, &c| acc * x + c)
+    }
+
+    // compose
+    // 合成 self(rhs(x)) を返す.
+    pub fn compose(&self, rhs: &Univariate<F>) -> Univariate<F> {
+        self.coefs.iter().rev().fold(Univariate::zero(), |acc, &c| {
+            acc.mul(rhs).add(&Univariate::constant(c))
+        })
+    }
+
+    // from_poly
+    // 1変数の多項式Polynomial<F, 1>から変換する.
+    pub fn from_poly(poly: &Polynomial<F, 1>) -> Univariate<F> {
+        Univariate::from_variable(poly, 0)
+    }
+
+    // from_variable
+    // k番目(0から数える)の変数のみを含むN変数の多項式から, その変数の1変数多項式に変換する.
+    pub fn from_variable<const N: usize>(poly: &Polynomial<F, N>, k: usize) -> Univariate<F> {
+        let mut coefs = vec![F::zero(); poly.total_degree() as usize + 1];
+        for mono in poly.monos.iter() {
+            coefs[mono.degree[k] as usize] = coefs[mono.degree[k] as usize] + mono.coef;
+        }
+        Univariate::new(coefs)
+    }
+
+    // to_poly
+    // 1変数の多項式Polynomial<F, 1>に変換する. 単項式は次数の大きいものから並ぶ.
+    pub fn to_poly(&self) -> Polynomial<F, 1> {
+        Polynomial::new(
+            self.coefs
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, &c)| c != F::zero())
+                .map(|(i, &c)| Monomial::new(c, [i as u32]))
+                .collect(),
+        )
+    }
+}
+
+// coefficients_in
+// x_kについての1変数多項式を係数とし, 他の変数の次数(k番目は0)を添字とする表に分ける.
+pub fn coefficients_in<F, const N: usize>(
+    poly: &Polynomial<F, N>,
+    k: usize,
+) -> BTreeMap<[u32; N], Univariate<F>>
+where
+    F: field::Field,
+{
+    let mut ans: BTreeMap<[u32; N], Univariate<F>> = BTreeMap::new();
+    for mono in poly.monos.iter() {
+        let mut alpha = mono.degree;
+        alpha[k] = 0;
+        let mut coefs = vec![F::zero(); mono.degree[k] as usize + 1];
+        coefs[mono.degree[k] as usize] = mono.coef;
+        let entry = ans.entry(alpha).or_insert_with(Univariate::zero);
+        *entry = entry.add(&Univariate::new(coefs));
+    }
+    ans
+}
+
+// embed
+// x_kの1変数多項式に, 他の変数の単項式 x^alpha をかけたN変数多項式を返す.
+pub fn embed<F, const N: usize>(u: &Univariate<F>, k: usize, alpha: [u32; N]) -> Polynomial<F, N>
+where
+    F: field::Field,
+{
+    let mut poly = Polynomial::new(
+        u.coefs
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c != F::zero())
+            .map(|(i, &c)| {
+                let mut degree = alpha;
+                degree[k] = i as u32;
+                Monomial::new(c, degree)
+            })
+            .collect(),
+    );
+    poly.arrange();
+    poly
+}
+
+impl<F> fmt::Display for Univariate<F>
+where
+    F: field::Field + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", self.to_poly())
+        }
+    }
+}
+
+fn pow<F>(base: F, exp: usize) -> F
+where
+    F: field::Field,
+{
+    (0..exp).fold(F::unit(), |acc, _| acc * base)
+}
+
+impl<const P: i32> Univariate<Fp<P>> {
+    // roots
+    // F_P における根を重複なく返す.
+    // gcd(f, x^P - x) で相異なる1次式の積を取り出し, 等次数分解で1次式に分ける.
+    pub fn roots(&self) -> Vec<Fp<P>> {
+        if self.is_zero() {
+            return vec![];
+        }
+        let x = Univariate::x();
+        let g = self.gcd(&x.pow_mod(P as usize, self).sub(&x));
+        let mut roots = vec![];
+        split_linear(g, &mut roots);
+        roots
+    }
+}
+
+// split_linear
+// 相異なる1次式の積であるモニックなgを分解し, 根をrootsに加える.
+// aを順に変えて gcd(g, (x+a)^((P-1)/2) - 1) を求め, 自明でない因子が見つかれば再帰的に分解する.
+// 相異なる2根r, sについて, r+aとs+aの平方剰余性が異なるaが存在するので必ず分解できる.
+fn split_linear<const P: i32>(g: Univariate<Fp<P>>, roots: &mut Vec<Fp<P>>) {
+    match g.degree() {
+        0 => {}
+        1 => roots.push(-g.coefs[0] / g.coefs[1]),
+        _ if P == 2 => {
+            // x(x+1) のみ
+            roots.push(Fp::zero());
+            roots.push(Fp::unit());
+        }
+        _ => {
+            for a in 0..P {
+                let shift = Univariate::new(vec![Fp::new(a), Fp::unit()]);
+                let h = g.gcd(
+                    &shift
+                        .pow_mod((P as usize - 1) / 2, &g)
+                        .sub(&Univariate::constant(Fp::unit())),
+                );
+                if h.degree() > 0 && h.degree() < g.degree() {
+                    let (rest, _) = g.div_rem(&h);
+                    split_linear(h, roots);
+                    split_linear(rest, roots);
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Command
+// 対話シェルに入力される1行分の命令を表す.
+// 多項式を受け取る引数は変数名か多項式の式のどちらかを文字列のまま保持し, セッション側で解釈する.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Ring(i32, usize),
+    Let(String, String),
+    Ideal(String, Vec<String>),
+    Grobner(String),
+    Reduce(String, String),
+    Member(String, String),
+    Print(String),
+    List,
+    Help,
+    Quit,
+}
+
+pub const HELP: &str = "\
+ring <p> <n>              : F_p[x_1, ..., x_n] を定義する (これまでの変数は消える)
+let <name> = <poly>       : 多項式に名前をつける
+ideal <name> = <poly>, ...: 生成元を与えてイデアルに名前をつける
+grobner <ideal>           : 簡約グレブナー基底を表示する
+reduce <poly> mod <ideal> : 簡約グレブナー基底による正規形を表示する
+member <poly> in <ideal>  : 多項式がイデアルに属するか判定する
+print <name>              : 名前のついた多項式かイデアルを表示する
+list                      : 定義されている名前を一覧する
+help                      : この説明を表示する
+quit                      : 終了する";
+
+// parse
+// 1行を命令に変換する.
+pub fn parse(line: &str) -> Result<Command, &'static str> {
+    let line = line.trim();
+    let (head, rest) = match line.split_once(' ') {
+        Some((head, rest)) => (head, rest.trim()),
+        None => (line, ""),
+    };
+    match head {
+        "ring" => {
+            let args: Vec<&str> = rest.split_whitespace().collect();
+            if args.len() != 2 {
+                return Err("usage: ring <p> <n>");
+            }
+            let p = args[0].parse().map_err(|_| "invalid characteristic")?;
+            let n = args[1].parse().map_err(|_| "invalid number of variables")?;
+            Ok(Command::Ring(p, n))
+        }
+        "let" => {
+            let (name, expr) = assignment(rest)?;
+            Ok(Command::Let(name, expr.to_string()))
+        }
+        "ideal" => {
+            let (name, gens) = assignment(rest)?;
+            Ok(Command::Ideal(
+                name,
+                gens.split(',').map(|gen| gen.trim().to_string()).collect(),
+            ))
+        }
+        "grobner" => Ok(Command::Grobner(name(rest)?)),
+        "reduce" => match rest.rsplit_once(" mod ") {
+            Some((poly, ideal)) => Ok(Command::Reduce(poly.trim().to_string(), name(ideal)?)),
+            None => Err("usage: reduce <poly> mod <ideal>"),
+        },
+        "member" => match rest.rsplit_once(" in ") {
+            Some((poly, ideal)) => Ok(Command::Member(poly.trim().to_string(), name(ideal)?)),
+            None => Err("usage: member <poly> in <ideal>"),
+        },
+        "print" => Ok(Command::Print(name(rest)?)),
+        "list" => Ok(Command::List),
+        "help" => Ok(Command::Help),
+        "quit" | "exit" => Ok(Command::Quit),
+        _ => Err("unknown command, type 'help'"),
+    }
+}
+
+// is_name
+// 変数名として使える文字列か判定する. 多項式の式と区別するため, 'x'から始まる名前は使えない.
+pub fn is_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() && c != 'x' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+fn name(s: &str) -> Result<String, &'static str> {
+    let s = s.trim();
+    if is_name(s) {
+        Ok(s.to_string())
+    } else {
+        Err("invalid name")
+    }
+}
+
+fn assignment(s: &str) -> Result<(String, &str), &'static str> {
+    match s.split_once('=') {
+        Some((lhs, rhs)) if !rhs.trim().is_empty() => Ok((name(lhs)?, rhs.trim())),
+        _ => Err("usage: <name> = <expr>"),
+    }
+}
@@ -0,0 +1,6 @@
+pub mod command;
+pub mod session;
+pub mod shell;
+
+#[cfg(test)]
+mod test;
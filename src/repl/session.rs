@@ -0,0 +1,149 @@
+use super::command::{is_name, Command};
+use crate::algebra::fp;
+use crate::buchberger::grobner;
+use crate::equation_parser::parser;
+use crate::polynomial::poly::{self, join, show, Polynomial};
+use std::collections::BTreeMap;
+
+// Workspace
+// 環を固定したセッションを環の型によらず扱うためのトレイト.
+pub trait Workspace {
+    fn ring(&self) -> String;
+    fn execute(&mut self, command: &Command) -> Result<String, &'static str>;
+}
+
+// Ideal
+// 名前のついたイデアル. 簡約グレブナー基底は必要になったときに一度だけ計算して保持する.
+struct Ideal<const P: i32, const N: usize> {
+    gens: Vec<Polynomial<fp::Fp<P>, N>>,
+    basis: Option<Vec<Polynomial<fp::Fp<P>, N>>>,
+}
+
+// Session
+// F_P[x_1, ..., x_N]上で名前のついた多項式とイデアルを保持する.
+pub struct Session<const P: i32, const N: usize> {
+    polys: BTreeMap<String, Polynomial<fp::Fp<P>, N>>,
+    ideals: BTreeMap<String, Ideal<P, N>>,
+}
+
+impl<const P: i32, const N: usize> Session<P, N> {
+    pub fn new() -> Session<P, N> {
+        Session {
+            polys: BTreeMap::new(),
+            ideals: BTreeMap::new(),
+        }
+    }
+
+    // polynomial
+    // 名前か多項式の式から多項式を得る. 式は入力の順に単項式が並ぶので並べ直しておく.
+    fn polynomial(&self, expr: &str) -> Result<Polynomial<fp::Fp<P>, N>, &'static str> {
+        if is_name(expr) {
+            return self.polys.get(expr).cloned().ok_or("undefined polynomial");
+        }
+        let mut polys = parser::parse::<P, N>(expr)?;
+        if polys.len() != 1 {
+            return Err("expected a single polynomial");
+        }
+        let mut poly = polys.pop().unwrap();
+        poly.arrange();
+        Ok(poly)
+    }
+
+    fn basis(&mut self, name: &str) -> Result<&Vec<Polynomial<fp::Fp<P>, N>>, &'static str> {
+        let ideal = self.ideals.get_mut(name).ok_or("undefined ideal")?;
+        if ideal.basis.is_none() {
+            ideal.basis = Some(grobner::reduced_grobner(ideal.gens.clone()));
+        }
+        Ok(ideal.basis.as_ref().unwrap())
+    }
+
+    fn normal_form(
+        &mut self,
+        expr: &str,
+        name: &str,
+    ) -> Result<Polynomial<fp::Fp<P>, N>, &'static str> {
+        let poly = self.polynomial(expr)?;
+        let (_, poly) = poly::simplify(poly, self.basis(name)?);
+        Ok(poly)
+    }
+}
+
+impl<const P: i32, const N: usize> Default for Session<P, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const P: i32, const N: usize> Workspace for Session<P, N> {
+    fn ring(&self) -> String {
+        format!(
+            "F_{}[{}]",
+            P,
+            (1..=N)
+                .map(|i| format!("x_{}", i))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    fn execute(&mut self, command: &Command) -> Result<String, &'static str> {
+        match command {
+            Command::Let(name, expr) => {
+                let poly = self.polynomial(expr)?;
+                let out = format!("{} = {}", name, show(&poly));
+                self.polys.insert(name.clone(), poly);
+                Ok(out)
+            }
+            Command::Ideal(name, exprs) => {
+                let gens = exprs
+                    .iter()
+                    .map(|expr| self.polynomial(expr))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let out = format!("{} = ({})", name, join(&gens));
+                self.ideals
+                    .insert(name.clone(), Ideal { gens, basis: None });
+                Ok(out)
+            }
+            Command::Grobner(name) => Ok(format!("[{}]", join(self.basis(name)?))),
+            Command::Reduce(expr, name) => Ok(show(&self.normal_form(expr, name)?)),
+            Command::Member(expr, name) => Ok(self.normal_form(expr, name)?.is_zero().to_string()),
+            Command::Print(name) => {
+                if let Some(poly) = self.polys.get(name) {
+                    Ok(show(poly))
+                } else if let Some(ideal) = self.ideals.get(name) {
+                    Ok(format!("({})", join(&ideal.gens)))
+                } else {
+                    Err("undefined name")
+                }
+            }
+            Command::List => Ok(self
+                .polys
+                .keys()
+                .map(|name| format!("{} : polynomial", name))
+                .chain(self.ideals.keys().map(|name| format!("{} : ideal", name)))
+                .collect::<Vec<String>>()
+                .join("\n")),
+            _ => Err("not a session command"),
+        }
+    }
+}
+
+// new_workspace
+// F_p[x_1, ..., x_n]のセッションを作る. 環は型で固定されるため, 対応している p, n の組み合わせに限る.
+pub fn new_workspace(p: i32, n: usize) -> Result<Box<dyn Workspace>, &'static str> {
+    macro_rules! dispatch {
+        ($($p:literal),*) => {
+            match p {
+                $($p => match n {
+                    1 => Ok(Box::new(Session::<$p, 1>::new())),
+                    2 => Ok(Box::new(Session::<$p, 2>::new())),
+                    3 => Ok(Box::new(Session::<$p, 3>::new())),
+                    4 => Ok(Box::new(Session::<$p, 4>::new())),
+                    _ => Err("number of variables must be between 1 and 4"),
+                },)*
+                _ => Err("characteristic must be one of 2, 3, 5, 7, 11, 13"),
+            }
+        };
+    }
+    dispatch!(2, 3, 5, 7, 11, 13)
+}
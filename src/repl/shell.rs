@@ -0,0 +1,56 @@
+use super::command::{self, Command};
+use super::session::{self, Workspace};
+use std::io::{self, BufRead, Write};
+
+// Shell
+// 対話シェルの状態. 現在の環のセッションを保持し, 環の定義, ヘルプ, 終了以外の命令はセッションに任せる.
+pub struct Shell {
+    workspace: Box<dyn Workspace>,
+}
+
+impl Shell {
+    pub fn new(p: i32, n: usize) -> Result<Shell, &'static str> {
+        Ok(Shell {
+            workspace: session::new_workspace(p, n)?,
+        })
+    }
+
+    pub fn ring(&self) -> String {
+        self.workspace.ring()
+    }
+
+    // execute
+    // 1行を実行して表示する文字列を返す. 終了命令の場合はNoneを返す.
+    pub fn execute(&mut self, line: &str) -> Result<Option<String>, &'static str> {
+        match command::parse(line)? {
+            Command::Ring(p, n) => {
+                self.workspace = session::new_workspace(p, n)?;
+                Ok(Some(self.workspace.ring()))
+            }
+            Command::Help => Ok(Some(command::HELP.to_string())),
+            Command::Quit => Ok(None),
+            command => self.workspace.execute(&command).map(Some),
+        }
+    }
+}
+
+// run
+// inputから1行ずつ命令を読み, 結果をoutputに書き出す. 空行は無視し, エラーが起きても続ける.
+pub fn run<R: BufRead, W: Write>(mut shell: Shell, input: R, mut output: W) -> io::Result<()> {
+    writeln!(output, "{}", shell.ring())?;
+    write!(output, "> ")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            match shell.execute(&line) {
+                Ok(Some(out)) => writeln!(output, "{}", out)?,
+                Ok(None) => return Ok(()),
+                Err(msg) => writeln!(output, "error: {}", msg)?,
+            }
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
@@ -0,0 +1,56 @@
+use super::command::{self, Command};
+use super::shell::{self, Shell};
+
+#[test]
+fn parse_command() {
+    assert_eq!(command::parse("ring 5 2"), Ok(Command::Ring(5, 2)));
+    assert_eq!(
+        command::parse("ideal I = f, x_1 + 1"),
+        Ok(Command::Ideal(
+            "I".to_string(),
+            vec!["f".to_string(), "x_1 + 1".to_string()]
+        ))
+    );
+    assert_eq!(
+        command::parse("member x_1^2 + 4 in I"),
+        Ok(Command::Member("x_1^2 + 4".to_string(), "I".to_string()))
+    );
+    assert!(command::parse("let x = x_1").is_err());
+    assert!(command::parse("frobnicate").is_err());
+}
+
+#[test]
+fn session() {
+    let mut shell = Shell::new(5, 2).unwrap();
+    let mut run = |line: &str| shell.execute(line).unwrap().unwrap();
+    run("let f = x_1^2x_2 + 4");
+    run("ideal I = f, x_1x_2^2 + 4x_1");
+    assert_eq!(run("grobner I"), "[x_1^2+4x_2^1, x_2^2+4]");
+    assert_eq!(run("reduce x_1^2 mod I"), "x_2^1");
+    assert_eq!(run("member x_2^2 + 4 in I"), "true");
+    assert_eq!(run("member x_1 in I"), "false");
+    assert_eq!(run("ring 3 1"), "F_3[x_1]");
+    assert!(shell.execute("print f").is_err());
+    assert!(shell.execute("let g = x_2").is_err());
+}
+
+#[test]
+fn run_script() {
+    let input = "let f = x_1 + 1\n\nprint f\nquit\nprint f\n";
+    let mut output = Vec::new();
+    shell::run(Shell::new(7, 1).unwrap(), input.as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "F_7[x_1]\n> f = x_1^1+1\n> > x_1^1+1\n> "
+    );
+}
+
+#[test]
+fn unordered_input() {
+    let mut shell = Shell::new(7, 2).unwrap();
+    let out = shell
+        .execute("let f = 3 + x_2 + x_1 + 2x_1")
+        .unwrap()
+        .unwrap();
+    assert_eq!(out, "f = 3x_1^1+x_2^1+3");
+}
@@ -0,0 +1,4 @@
+pub mod solve;
+
+#[cfg(test)]
+mod test;
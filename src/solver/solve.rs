@@ -0,0 +1,137 @@
+use crate::algebra::field::Field;
+use crate::algebra::fp::Fp;
+use crate::ideal::operation::Ideal;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+use crate::polynomial::univariate::Univariate;
+
+// Solution
+// 連立方程式の解の1つ. multiplicityはその点における局所環の次元(重複度)を表す.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution<const P: i32, const N: usize> {
+    pub point: [Fp<P>; N],
+    pub multiplicity: usize,
+}
+
+// Solutions
+// 連立方程式のF_P^Nにおける解全体.
+// is_emptyは簡約グレブナー基底が{1}, すなわち代数閉包上でも解がないときにtrueになる.
+// is_emptyがfalseでもF_P上の解がなくsolutionsが空になることがある.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solutions<const P: i32, const N: usize> {
+    pub is_empty: bool,
+    pub solutions: Vec<Solution<P, N>>,
+}
+
+// solve
+// polysを0とする連立方程式のF_P^Nにおける解をすべて求める.
+// 辞書式順序のグレブナー基底を求め, 最後の変数から順に1変数多項式の根を求めて代入していく.
+// 根は, 既に決まった変数の値を代入した基底の元の最大公約数から求める.
+// イデアルが0次元でない場合はエラーを返す.
+pub fn solve<const P: i32, const N: usize>(
+    polys: Vec<Polynomial<Fp<P>, N>>,
+) -> Result<Solutions<P, N>, &'static str> {
+    let ideal = Ideal::new(polys);
+    if ideal.is_unit() {
+        return Ok(Solutions {
+            is_empty: true,
+            solutions: vec![],
+        });
+    }
+    if !ideal.is_zero_dimensional() {
+        return Err("ideal is not zero-dimensional");
+    }
+    let mut points = vec![[Fp::zero(); N]];
+    for k in (0..N).rev() {
+        // x_{k+1}, ..., x_N のみを含む基底の元
+        let eliminant: Vec<&Polynomial<Fp<P>, N>> = ideal
+            .basis
+            .iter()
+            .filter(|f| {
+                f.monos
+                    .iter()
+                    .all(|mono| mono.degree[..k].iter().all(|&deg| deg == 0))
+            })
+            .collect();
+        let mut next = Vec::new();
+        for point in points.iter() {
+            let values: Vec<(usize, Fp<P>)> = (k + 1..N).map(|i| (i, point[i])).collect();
+            let g = eliminant.iter().fold(Univariate::zero(), |g, f| {
+                g.gcd(&Univariate::from_variable(&f.partial_eval(&values), k))
+            });
+            for a in g.roots() {
+                let mut point = *point;
+                point[k] = a;
+                next.push(point);
+            }
+        }
+        points = next;
+    }
+    let solutions = points
+        .into_iter()
+        .map(|point| Solution {
+            point,
+            multiplicity: multiplicity(&ideal, &point),
+        })
+        .collect();
+    Ok(Solutions {
+        is_empty: false,
+        solutions,
+    })
+}
+
+// multiplicity
+// 0次元イデアルIの点aにおける重複度を求める. 点aに対応する極大イデアルをmとすると,
+// dim R/(I + m^k) はkについて単調増加し, 局所環の次元に達すると止まるので, 止まるまでkを増やす.
+// aが原点になるように平行移動しておくと, m^k は次数kの単項式 C(N+k-1, k) 個で生成される.
+fn multiplicity<const P: i32, const N: usize>(
+    ideal: &Ideal<Fp<P>, N>,
+    point: &[Fp<P>; N],
+) -> usize {
+    let shift: [Polynomial<Fp<P>, N>; N] =
+        std::array::from_fn(|i| Polynomial::variable(i).add(&Polynomial::constant(point[i])));
+    let translated: Vec<Polynomial<Fp<P>, N>> =
+        ideal.basis.iter().map(|f| f.substitute(&shift)).collect();
+    let mut prev = 0;
+    for k in 1.. {
+        let gens = translated
+            .iter()
+            .cloned()
+            .chain(monomials::<P, N>(k))
+            .collect();
+        let dim = Ideal::new(gens).quotient_dimension().unwrap();
+        if dim == prev {
+            return dim;
+        }
+        prev = dim;
+    }
+    unreachable!()
+}
+
+// monomials
+// 全次数kの単項式をすべて返す.
+fn monomials<const P: i32, const N: usize>(k: u32) -> Vec<Polynomial<Fp<P>, N>> {
+    let mut degrees = vec![[0; N]];
+    for i in 0..N {
+        degrees = degrees
+            .into_iter()
+            .flat_map(|degree| {
+                let used: u32 = degree.iter().sum();
+                let range = if i == N - 1 {
+                    k - used..=k - used
+                } else {
+                    0..=k - used
+                };
+                range.map(move |d| {
+                    let mut degree = degree;
+                    degree[i] = d;
+                    degree
+                })
+            })
+            .collect();
+    }
+    degrees
+        .into_iter()
+        .map(|degree| Polynomial::new(vec![Monomial::new(Fp::unit(), degree)]))
+        .collect()
+}
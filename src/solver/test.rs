@@ -0,0 +1,92 @@
+use super::solve::{solve, Solution};
+use crate::algebra::fp;
+use crate::polynomial::monomial::Monomial;
+use crate::polynomial::poly::Polynomial;
+
+type Fp = fp::Fp<5>;
+
+fn var(i: usize) -> Polynomial<Fp, 2> {
+    Polynomial::variable(i)
+}
+
+fn constant(c: i32) -> Polynomial<Fp, 2> {
+    Polynomial::constant(Fp::new(c))
+}
+
+#[test]
+fn solve_test() {
+    // x^2 y = 1, x y^2 = x の解は (±1, 1) と (±2, -1)
+    let polys = vec![
+        var(0).mul_poly(&var(0)).mul_poly(&var(1)).sub(&constant(1)),
+        var(0).mul_poly(&var(1)).mul_poly(&var(1)).sub(&var(0)),
+    ];
+    let solutions = solve(polys).unwrap();
+    assert!(!solutions.is_empty);
+    let mut points: Vec<[Fp; 2]> = solutions.solutions.iter().map(|s| s.point).collect();
+    points.sort_by_key(|p| format!("{}{}", p[0], p[1]));
+    assert_eq!(
+        points,
+        vec![
+            [Fp::new(1), Fp::new(1)],
+            [Fp::new(2), Fp::new(4)],
+            [Fp::new(3), Fp::new(4)],
+            [Fp::new(4), Fp::new(1)],
+        ]
+    );
+    assert!(solutions.solutions.iter().all(|s| s.multiplicity == 1));
+}
+
+#[test]
+fn multiplicity() {
+    // x^2 = 0, y = x + 1 は (0, 1) に重複度2の解を持つ
+    let polys = vec![
+        var(0).mul_poly(&var(0)),
+        var(1).sub(&var(0)).sub(&constant(1)),
+    ];
+    assert_eq!(
+        solve(polys).unwrap().solutions,
+        vec![Solution {
+            point: [Fp::new(0), Fp::new(1)],
+            multiplicity: 2
+        }]
+    );
+    // x^2 = xy = y^2 = 0 は原点に重複度3の解を持つ
+    let polys = vec![
+        var(0).mul_poly(&var(0)),
+        var(0).mul_poly(&var(1)),
+        var(1).mul_poly(&var(1)),
+    ];
+    assert_eq!(solve(polys).unwrap().solutions[0].multiplicity, 3);
+}
+
+#[test]
+fn degenerate() {
+    let empty = solve(vec![var(0), var(0).sub(&constant(1))]).unwrap();
+    assert!(empty.is_empty);
+    assert!(empty.solutions.is_empty());
+
+    // x^2 = 2 は F_5 に解を持たない
+    let none = solve(vec![var(0).mul_poly(&var(0)).sub(&constant(2)), var(1)]).unwrap();
+    assert!(!none.is_empty);
+    assert!(none.solutions.is_empty());
+
+    assert!(solve(vec![var(0).mul_poly(&var(1))]).is_err());
+}
+
+#[test]
+fn high_multiplicity() {
+    // (x^4, y^4, z^4) の解は原点のみで, 重複度は 4^3 = 64
+    let power = |i: usize| {
+        let mut degree = [0; 3];
+        degree[i] = 4;
+        Polynomial::<fp::Fp<7>, 3>::new(vec![Monomial::new(fp::Fp::new(1), degree)])
+    };
+    let solutions = solve(vec![power(0), power(1), power(2)]).unwrap();
+    assert_eq!(
+        solutions.solutions,
+        vec![Solution {
+            point: [fp::Fp::new(0); 3],
+            multiplicity: 64
+        }]
+    );
+}